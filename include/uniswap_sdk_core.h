@@ -0,0 +1,304 @@
+#ifndef UNISWAP_SDK_CORE_H
+#define UNISWAP_SDK_CORE_H
+
+/* Generated by cbindgen from src/capi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The result of a call, where everything but [`UscErrorCode::Ok`] is an error.
+//
+// The codes below 100 are the [`Error::code`]s of the SDK errors.
+typedef enum UscErrorCode {
+  USC_ERROR_CODE_OK = 0,
+  USC_ERROR_CODE_CHAIN_ID_MISMATCH = 1,
+  USC_ERROR_CODE_EQUAL_ADDRESSES = 2,
+  USC_ERROR_CODE_UINT_OVERFLOW = 3,
+  USC_ERROR_CODE_CURRENCY_MISMATCH = 4,
+  USC_ERROR_CODE_INVALID = 5,
+  USC_ERROR_CODE_ZERO_CHAIN_ID = 6,
+  USC_ERROR_CODE_ZERO_ADDRESS = 7,
+  USC_ERROR_CODE_INVALID_ADDRESS = 8,
+  USC_ERROR_CODE_CHECKSUM_MISMATCH = 9,
+  USC_ERROR_CODE_FEE_BPS_OUT_OF_RANGE = 10,
+  USC_ERROR_CODE_DECIMALS_OUT_OF_RANGE = 11,
+  USC_ERROR_CODE_ZERO_QUOTE = 12,
+  USC_ERROR_CODE_DIVISION_BY_ZERO = 13,
+  USC_ERROR_CODE_PARSE = 14,
+  USC_ERROR_CODE_UNSUPPORTED_CHAIN_ID = 15,
+  USC_ERROR_CODE_MISSING_WRAPPED_NATIVE = 16,
+  USC_ERROR_CODE_ZERO_SIGNIFICANT_DIGITS = 17,
+  USC_ERROR_CODE_NEGATIVE = 18,
+  USC_ERROR_CODE_EMPTY = 19,
+  USC_ERROR_CODE_INSUFFICIENT_BALANCE = 20,
+  USC_ERROR_CODE_OUT_OF_RANGE = 21,
+  USC_ERROR_CODE_INVALID_PATH = 22,
+  USC_ERROR_CODE_ZERO_PERIODS = 23,
+  USC_ERROR_CODE_UNKNOWN_FEE_AMOUNT = 24,
+  USC_ERROR_CODE_DUPLICATE_CURRENCY = 25,
+  // A required pointer argument is null.
+  USC_ERROR_CODE_NULL_POINTER = 100,
+  // A string argument is not valid UTF-8.
+  USC_ERROR_CODE_INVALID_ARGUMENT = 101,
+  // The library panicked.
+  USC_ERROR_CODE_PANIC = 102,
+} UscErrorCode;
+
+// The rounding mode of the formatting functions, which take it as a `uint32_t` and reject other
+// values with `InvalidArgument`.
+typedef enum UscRounding {
+  USC_ROUNDING_ROUND_DOWN = 0,
+  USC_ROUNDING_ROUND_HALF_UP = 1,
+  USC_ROUNDING_ROUND_UP = 2,
+} UscRounding;
+
+// A token or the native currency of a chain.
+typedef struct UscCurrency UscCurrency;
+
+// An amount of a currency.
+typedef struct UscCurrencyAmount UscCurrencyAmount;
+
+// The price of a base currency in terms of a quote currency.
+typedef struct UscPrice UscPrice;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Returns the error message of the last failed call on this thread, or null if there is none.
+//
+// The message is owned by the library and valid until the next failed call on this thread.
+const char *usc_last_error_message(void);
+
+// Frees a string returned by the library.
+//
+// # Safety
+//
+// `string` must be null or a string returned by the library that was not freed yet.
+void usc_string_free(char *string);
+
+// Creates a token, validating its address and decimals.
+//
+// # Safety
+//
+// `address` must point to a NUL-terminated string, `symbol` and `name` must be null or point to
+// NUL-terminated strings, and `out` must be valid for writes.
+enum UscErrorCode usc_token_new(uint64_t chain_id,
+                                const char *address,
+                                uint8_t decimals,
+                                const char *symbol,
+                                const char *name,
+                                struct UscCurrency **out);
+
+// Creates the native currency of the chain.
+//
+// # Safety
+//
+// `out` must be valid for writes.
+enum UscErrorCode usc_native_currency_new(uint64_t chain_id, struct UscCurrency **out);
+
+// Returns the wrapped token of the native currency, or a copy of the token.
+//
+// # Safety
+//
+// `currency` must be a valid handle and `out` must be valid for writes.
+enum UscErrorCode usc_currency_wrapped(const struct UscCurrency *currency,
+                                       struct UscCurrency **out);
+
+// Writes the checksummed address of the token, or of the wrapped native token.
+//
+// # Safety
+//
+// `currency` must be a valid handle and `out` must be valid for writes.
+enum UscErrorCode usc_currency_address(const struct UscCurrency *currency, char **out);
+
+// Returns the decimals of the currency, or 0 for a null handle.
+//
+// # Safety
+//
+// `currency` must be null or a valid handle.
+uint8_t usc_currency_decimals(const struct UscCurrency *currency);
+
+// Frees a currency handle.
+//
+// # Safety
+//
+// `currency` must be null or a handle returned by the library that was not freed yet.
+void usc_currency_free(struct UscCurrency *currency);
+
+// Creates an amount from the raw amount of the smallest unit of the currency, given as a
+// decimal string.
+//
+// # Safety
+//
+// `currency` must be a valid handle, `raw_amount` must point to a NUL-terminated string and
+// `out` must be valid for writes.
+enum UscErrorCode usc_amount_from_raw(const struct UscCurrency *currency,
+                                      const char *raw_amount,
+                                      struct UscCurrencyAmount **out);
+
+// Adds two amounts of the same currency.
+//
+// # Safety
+//
+// `a` and `b` must be valid handles and `out` must be valid for writes.
+enum UscErrorCode usc_amount_add(const struct UscCurrencyAmount *a,
+                                 const struct UscCurrencyAmount *b,
+                                 struct UscCurrencyAmount **out);
+
+// Subtracts an amount of the same currency.
+//
+// # Safety
+//
+// `a` and `b` must be valid handles and `out` must be valid for writes.
+enum UscErrorCode usc_amount_subtract(const struct UscCurrencyAmount *a,
+                                      const struct UscCurrencyAmount *b,
+                                      struct UscCurrencyAmount **out);
+
+// Multiplies an amount by the fraction `numerator / denominator`, given as decimal strings.
+//
+// # Safety
+//
+// `amount` must be a valid handle, `numerator` and `denominator` must point to NUL-terminated
+// strings and `out` must be valid for writes.
+enum UscErrorCode usc_amount_multiply(const struct UscCurrencyAmount *amount,
+                                      const char *numerator,
+                                      const char *denominator,
+                                      struct UscCurrencyAmount **out);
+
+// Divides an amount by the fraction `numerator / denominator`, given as decimal strings.
+//
+// # Safety
+//
+// `amount` must be a valid handle, `numerator` and `denominator` must point to NUL-terminated
+// strings and `out` must be valid for writes.
+enum UscErrorCode usc_amount_divide(const struct UscCurrencyAmount *amount,
+                                    const char *numerator,
+                                    const char *denominator,
+                                    struct UscCurrencyAmount **out);
+
+// Writes the raw amount, rounded towards zero, as a decimal string.
+//
+// # Safety
+//
+// `amount` must be a valid handle and `out` must be valid for writes.
+enum UscErrorCode usc_amount_quotient(const struct UscCurrencyAmount *amount, char **out);
+
+// Writes the exact amount in units of the currency.
+//
+// # Safety
+//
+// `amount` must be a valid handle and `out` must be valid for writes.
+enum UscErrorCode usc_amount_to_exact(const struct UscCurrencyAmount *amount, char **out);
+
+// Writes the amount in units of the currency with `significant_digits` significant digits.
+//
+// # Safety
+//
+// `amount` must be a valid handle and `out` must be valid for writes.
+enum UscErrorCode usc_amount_to_significant(const struct UscCurrencyAmount *amount,
+                                            uint8_t significant_digits,
+                                            uint32_t rounding,
+                                            char **out);
+
+// Writes the amount in units of the currency with `decimal_places` decimal places.
+//
+// # Safety
+//
+// `amount` must be a valid handle and `out` must be valid for writes.
+enum UscErrorCode usc_amount_to_fixed(const struct UscCurrencyAmount *amount,
+                                      uint8_t decimal_places,
+                                      uint32_t rounding,
+                                      char **out);
+
+// Frees an amount handle.
+//
+// # Safety
+//
+// `amount` must be null or a handle returned by the library that was not freed yet.
+void usc_amount_free(struct UscCurrencyAmount *amount);
+
+// Creates the price at which `denominator` raw base units trade for `numerator` raw quote
+// units, given as decimal strings.
+//
+// # Safety
+//
+// `base` and `quote` must be valid handles, `denominator` and `numerator` must point to
+// NUL-terminated strings and `out` must be valid for writes.
+enum UscErrorCode usc_price_new(const struct UscCurrency *base,
+                                const struct UscCurrency *quote,
+                                const char *denominator,
+                                const char *numerator,
+                                struct UscPrice **out);
+
+// Inverts a price.
+//
+// # Safety
+//
+// `price` must be a valid handle and `out` must be valid for writes.
+enum UscErrorCode usc_price_invert(const struct UscPrice *price, struct UscPrice **out);
+
+// Multiplies a price by another price whose base currency is the quote currency of the first.
+//
+// # Safety
+//
+// `a` and `b` must be valid handles and `out` must be valid for writes.
+enum UscErrorCode usc_price_multiply(const struct UscPrice *a,
+                                     const struct UscPrice *b,
+                                     struct UscPrice **out);
+
+// Returns the amount of quote currency corresponding to an amount of base currency.
+//
+// # Safety
+//
+// `price` and `amount` must be valid handles and `out` must be valid for writes.
+enum UscErrorCode usc_price_quote(const struct UscPrice *price,
+                                  const struct UscCurrencyAmount *amount,
+                                  struct UscCurrencyAmount **out);
+
+// Writes the price with `significant_digits` significant digits.
+//
+// # Safety
+//
+// `price` must be a valid handle and `out` must be valid for writes.
+enum UscErrorCode usc_price_to_significant(const struct UscPrice *price,
+                                           uint8_t significant_digits,
+                                           uint32_t rounding,
+                                           char **out);
+
+// Writes the price with `decimal_places` decimal places.
+//
+// # Safety
+//
+// `price` must be a valid handle and `out` must be valid for writes.
+enum UscErrorCode usc_price_to_fixed(const struct UscPrice *price,
+                                     uint8_t decimal_places,
+                                     uint32_t rounding,
+                                     char **out);
+
+// Frees a price handle.
+//
+// # Safety
+//
+// `price` must be null or a handle returned by the library that was not freed yet.
+void usc_price_free(struct UscPrice *price);
+
+// Writes the checksummed address of a contract on the chain.
+//
+// `map` is the name of an address map constant, e.g. `"V3_CORE_FACTORY_ADDRESSES"`. Fails with
+// [`UscErrorCode::Invalid`] if the map is unknown and with [`UscErrorCode::UnsupportedChainId`] if
+// it has no address for the chain.
+//
+// # Safety
+//
+// `map` must point to a NUL-terminated string and `out` must be valid for writes.
+enum UscErrorCode usc_address_lookup(const char *map, uint64_t chain_id, char **out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* UNISWAP_SDK_CORE_H */
@@ -1,8 +1,106 @@
 use crate::prelude::*;
-use alloy_primitives::address;
-use lazy_static::lazy_static;
+use alloy_primitives::{address, b256};
+use core::ops::Index;
+
+/// A map of chain IDs to values, laid out as a static table.
+///
+/// Unlike a `HashMap`, a [`ChainMap`] can be built in a `const` context, so the lookup tables of
+/// the SDK need neither an allocator nor lazy initialization. Lookups scan the table linearly,
+/// which is fast for the few dozen chains it holds.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ChainMap<V: 'static> {
+    entries: &'static [(u64, V)],
+}
+
+impl<V> ChainMap<V> {
+    /// Creates a map from a table of `(chain_id, value)` entries.
+    ///
+    /// The chain IDs should be unique. Otherwise lookups return the value of the first entry.
+    #[inline]
+    #[must_use]
+    pub const fn new(entries: &'static [(u64, V)]) -> Self {
+        Self { entries }
+    }
+
+    /// Returns the value for the chain ID, if any.
+    #[inline]
+    #[must_use]
+    pub const fn get(&self, chain_id: &u64) -> Option<&'static V> {
+        let entries = self.entries;
+        let mut i = 0;
+        while i < entries.len() {
+            if entries[i].0 == *chain_id {
+                return Some(&entries[i].1);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns whether the map has a value for the chain ID.
+    #[inline]
+    #[must_use]
+    pub const fn contains_key(&self, chain_id: &u64) -> bool {
+        self.get(chain_id).is_some()
+    }
+
+    /// Returns the number of chains in the map.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the map is empty.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the underlying `(chain_id, value)` table.
+    #[inline]
+    #[must_use]
+    pub const fn entries(&self) -> &'static [(u64, V)] {
+        self.entries
+    }
+
+    /// Returns an iterator over the chain IDs and their values, in table order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&'static u64, &'static V)> {
+        self.entries
+            .iter()
+            .map(|(chain_id, value)| (chain_id, value))
+    }
+
+    /// Returns an iterator over the chain IDs, in table order.
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &'static u64> {
+        self.entries.iter().map(|(chain_id, _)| chain_id)
+    }
+
+    /// Returns an iterator over the values, in table order.
+    #[inline]
+    pub fn values(&self) -> impl Iterator<Item = &'static V> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+}
 
-pub type AddressMap = HashMap<u64, Address>;
+impl<V> Index<&u64> for ChainMap<V> {
+    type Output = V;
+
+    /// Returns the value for the chain ID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map has no value for the chain ID.
+    #[inline]
+    fn index(&self, chain_id: &u64) -> &V {
+        self.get(chain_id).expect("chain ID not in map")
+    }
+}
+
+pub type AddressMap = ChainMap<Address>;
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub struct ChainAddresses {
@@ -25,152 +123,148 @@ pub struct ChainAddresses {
 
 pub const DEFAULT_NETWORKS: [ChainId; 3] = [ChainId::MAINNET, ChainId::GOERLI, ChainId::SEPOLIA];
 
+/// Returns the entries of a map with the same address on all networks.
 #[inline]
-fn construct_same_address_map(address: Address, additional_networks: &[ChainId]) -> AddressMap {
-    let mut networks = DEFAULT_NETWORKS.to_vec();
-    networks.extend_from_slice(additional_networks);
-    AddressMap::from_iter(
-        networks
-            .into_iter()
-            .map(|chain_id| (chain_id as u64, address)),
-    )
+const fn same_address_entries<const N: usize>(
+    address: Address,
+    networks: [ChainId; N],
+) -> [(u64, Address); N] {
+    let mut entries = [(0, address); N];
+    let mut i = 0;
+    while i < N {
+        entries[i].0 = networks[i] as u64;
+        i += 1;
+    }
+    entries
 }
 
-lazy_static! {
-    pub static ref UNI_ADDRESSES: AddressMap = construct_same_address_map(
-        address!("0x1f9840a85d5aF5bf1D1762F925BDADdC4201F984"),
-        &[
-            ChainId::OPTIMISM,
-            ChainId::ARBITRUM_ONE,
-            ChainId::POLYGON,
-            ChainId::POLYGON_MUMBAI,
-            ChainId::SEPOLIA,
-        ]
-    );
-}
+pub const UNI_ADDRESSES: AddressMap = AddressMap::new(&same_address_entries(
+    address!("0x1f9840a85d5aF5bf1D1762F925BDADdC4201F984"),
+    [
+        ChainId::MAINNET,
+        ChainId::GOERLI,
+        ChainId::SEPOLIA,
+        ChainId::OPTIMISM,
+        ChainId::ARBITRUM_ONE,
+        ChainId::POLYGON,
+        ChainId::POLYGON_MUMBAI,
+    ],
+));
 
 pub const UNISWAP_NFT_AIRDROP_CLAIM_ADDRESS: Address =
     address!("0x8B799381ac40b838BBA4131ffB26197C432AFe78");
 
 pub const V2_FACTORY_ADDRESS: Address = address!("0x5C69bEe701ef814a2B6a3EDD4B1652CB9cc5aA6f");
 
-lazy_static! {
-    pub static ref V2_FACTORY_ADDRESSES: AddressMap = {
-        AddressMap::from_iter([
-            (ChainId::MAINNET as u64, V2_FACTORY_ADDRESS),
-            (ChainId::GOERLI as u64, V2_FACTORY_ADDRESS),
-            (
-                ChainId::SEPOLIA as u64,
-                address!("0xF62c03E08ada871A0bEb309762E260a7a6a880E6"),
-            ),
-            (
-                ChainId::OPTIMISM as u64,
-                address!("0x0c3c1c532F1e39EdF36BE9Fe0bE1410313E074Bf"),
-            ),
-            (
-                ChainId::ARBITRUM_ONE as u64,
-                address!("0xf1D7CC64Fb4452F05c498126312eBE29f30Fbcf9"),
-            ),
-            (
-                ChainId::AVALANCHE as u64,
-                address!("0x9e5A52f57b3038F1B8EeE45F28b3C1967e22799C"),
-            ),
-            (
-                ChainId::BASE_SEPOLIA as u64,
-                address!("0x7Ae58f10f7849cA6F5fB71b7f45CB416c9204b1e"),
-            ),
-            (
-                ChainId::BASE as u64,
-                address!("0x8909Dc15e40173Ff4699343b6eB8132c65e18eC6"),
-            ),
-            (
-                ChainId::BNB as u64,
-                address!("0x8909Dc15e40173Ff4699343b6eB8132c65e18eC6"),
-            ),
-            (
-                ChainId::POLYGON as u64,
-                address!("0x9e5A52f57b3038F1B8EeE45F28b3C1967e22799C"),
-            ),
-            (
-                ChainId::CELO as u64,
-                address!("0x79a530c8e2fA8748B7B40dd3629C0520c2cCf03f"),
-            ),
-            (
-                ChainId::BLAST as u64,
-                address!("0x5C346464d33F90bABaf70dB6388507CC889C1070"),
-            ),
-            (ChainId::WORLDCHAIN as u64, V2_FACTORY_ADDRESS),
-            (ChainId::UNICHAIN_SEPOLIA as u64, V2_FACTORY_ADDRESS),
-            (
-                ChainId::UNICHAIN as u64,
-                address!("0x1f98400000000000000000000000000000000002"),
-            ),
-            (
-                ChainId::MONAD_TESTNET as u64,
-                address!("0x733e88f248b742db6c14c0b1713af5ad7fdd59d0"),
-            ),
-        ])
-    };
-}
+pub const V2_FACTORY_ADDRESSES: AddressMap = AddressMap::new(&[
+    (ChainId::MAINNET as u64, V2_FACTORY_ADDRESS),
+    (ChainId::GOERLI as u64, V2_FACTORY_ADDRESS),
+    (
+        ChainId::SEPOLIA as u64,
+        address!("0xF62c03E08ada871A0bEb309762E260a7a6a880E6"),
+    ),
+    (
+        ChainId::OPTIMISM as u64,
+        address!("0x0c3c1c532F1e39EdF36BE9Fe0bE1410313E074Bf"),
+    ),
+    (
+        ChainId::ARBITRUM_ONE as u64,
+        address!("0xf1D7CC64Fb4452F05c498126312eBE29f30Fbcf9"),
+    ),
+    (
+        ChainId::AVALANCHE as u64,
+        address!("0x9e5A52f57b3038F1B8EeE45F28b3C1967e22799C"),
+    ),
+    (
+        ChainId::BASE_SEPOLIA as u64,
+        address!("0x7Ae58f10f7849cA6F5fB71b7f45CB416c9204b1e"),
+    ),
+    (
+        ChainId::BASE as u64,
+        address!("0x8909Dc15e40173Ff4699343b6eB8132c65e18eC6"),
+    ),
+    (
+        ChainId::BNB as u64,
+        address!("0x8909Dc15e40173Ff4699343b6eB8132c65e18eC6"),
+    ),
+    (
+        ChainId::POLYGON as u64,
+        address!("0x9e5A52f57b3038F1B8EeE45F28b3C1967e22799C"),
+    ),
+    (
+        ChainId::CELO as u64,
+        address!("0x79a530c8e2fA8748B7B40dd3629C0520c2cCf03f"),
+    ),
+    (
+        ChainId::BLAST as u64,
+        address!("0x5C346464d33F90bABaf70dB6388507CC889C1070"),
+    ),
+    (ChainId::WORLDCHAIN as u64, V2_FACTORY_ADDRESS),
+    (ChainId::UNICHAIN_SEPOLIA as u64, V2_FACTORY_ADDRESS),
+    (
+        ChainId::UNICHAIN as u64,
+        address!("0x1f98400000000000000000000000000000000002"),
+    ),
+    (
+        ChainId::MONAD_TESTNET as u64,
+        address!("0x733e88f248b742db6c14c0b1713af5ad7fdd59d0"),
+    ),
+]);
 
 pub const V2_ROUTER_ADDRESS: Address = address!("0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D");
 
-lazy_static! {
-    pub static ref V2_ROUTER_ADDRESSES: AddressMap = {
-        AddressMap::from_iter([
-            (ChainId::MAINNET as u64, V2_ROUTER_ADDRESS),
-            (ChainId::GOERLI as u64, V2_ROUTER_ADDRESS),
-            (
-                ChainId::ARBITRUM_ONE as u64,
-                address!("0x4752ba5dbc23f44d87826276bf6fd6b1c372ad24"),
-            ),
-            (
-                ChainId::OPTIMISM as u64,
-                address!("0x4a7b5da61326a6379179b40d00f57e5bbdc962c2"),
-            ),
-            (
-                ChainId::BASE_SEPOLIA as u64,
-                address!("0x1689E7B1F10000AE47eBfE339a4f69dECd19F602"),
-            ),
-            (
-                ChainId::BASE as u64,
-                address!("0x4752ba5dbc23f44d87826276bf6fd6b1c372ad24"),
-            ),
-            (
-                ChainId::AVALANCHE as u64,
-                address!("0x4752ba5dbc23f44d87826276bf6fd6b1c372ad24"),
-            ),
-            (
-                ChainId::BNB as u64,
-                address!("0x4752ba5dbc23f44d87826276bf6fd6b1c372ad24"),
-            ),
-            (
-                ChainId::POLYGON as u64,
-                address!("0xedf6066a2b290c185783862c7f4776a2c8077ad1"),
-            ),
-            (
-                ChainId::BLAST as u64,
-                address!("0xBB66Eb1c5e875933D44DAe661dbD80e5D9B03035"),
-            ),
-            (
-                ChainId::WORLDCHAIN as u64,
-                address!("0xf164fC0Ec4E93095b804a4795bBe1e041497b92a"),
-            ),
-            (
-                ChainId::UNICHAIN_SEPOLIA as u64,
-                address!("0x920b806E40A00E02E7D2b94fFc89860fDaEd3640"),
-            ),
-            (
-                ChainId::UNICHAIN as u64,
-                address!("0x284f11109359a7e1306c3e447ef14d38400063ff"),
-            ),
-            (
-                ChainId::MONAD_TESTNET as u64,
-                address!("0xfb8e1c3b833f9e67a71c859a132cf783b645e436"),
-            ),
-        ])
-    };
-}
+pub const V2_ROUTER_ADDRESSES: AddressMap = AddressMap::new(&[
+    (ChainId::MAINNET as u64, V2_ROUTER_ADDRESS),
+    (ChainId::GOERLI as u64, V2_ROUTER_ADDRESS),
+    (
+        ChainId::ARBITRUM_ONE as u64,
+        address!("0x4752ba5dbc23f44d87826276bf6fd6b1c372ad24"),
+    ),
+    (
+        ChainId::OPTIMISM as u64,
+        address!("0x4a7b5da61326a6379179b40d00f57e5bbdc962c2"),
+    ),
+    (
+        ChainId::BASE_SEPOLIA as u64,
+        address!("0x1689E7B1F10000AE47eBfE339a4f69dECd19F602"),
+    ),
+    (
+        ChainId::BASE as u64,
+        address!("0x4752ba5dbc23f44d87826276bf6fd6b1c372ad24"),
+    ),
+    (
+        ChainId::AVALANCHE as u64,
+        address!("0x4752ba5dbc23f44d87826276bf6fd6b1c372ad24"),
+    ),
+    (
+        ChainId::BNB as u64,
+        address!("0x4752ba5dbc23f44d87826276bf6fd6b1c372ad24"),
+    ),
+    (
+        ChainId::POLYGON as u64,
+        address!("0xedf6066a2b290c185783862c7f4776a2c8077ad1"),
+    ),
+    (
+        ChainId::BLAST as u64,
+        address!("0xBB66Eb1c5e875933D44DAe661dbD80e5D9B03035"),
+    ),
+    (
+        ChainId::WORLDCHAIN as u64,
+        address!("0xf164fC0Ec4E93095b804a4795bBe1e041497b92a"),
+    ),
+    (
+        ChainId::UNICHAIN_SEPOLIA as u64,
+        address!("0x920b806E40A00E02E7D2b94fFc89860fDaEd3640"),
+    ),
+    (
+        ChainId::UNICHAIN as u64,
+        address!("0x284f11109359a7e1306c3e447ef14d38400063ff"),
+    ),
+    (
+        ChainId::MONAD_TESTNET as u64,
+        address!("0xfb8e1c3b833f9e67a71c859a132cf783b645e436"),
+    ),
+]);
 
 impl ChainAddresses {
     /// Networks that share most of the same addresses i.e. Mainnet, Goerli, Optimism, Arbitrum,
@@ -524,191 +618,912 @@ const MONAD_TESTNET_ADDRESSES: ChainAddresses = ChainAddresses {
     ..ChainAddresses::default()
 };
 
-lazy_static! {
-    /// A map of chain IDs to their corresponding Uniswap contract addresses.
-    ///
-    /// This map is used to look up the addresses of various Uniswap contracts
-    /// for a given network. The keys in the map are the network IDs, and the values
-    /// are the corresponding contract addresses.
-    pub static ref CHAIN_TO_ADDRESSES_MAP: HashMap<u64, ChainAddresses> = {
-        HashMap::from_iter([
-            (ChainId::MAINNET as u64, MAINNET_ADDRESSES),
-            (ChainId::OPTIMISM as u64, OPTIMISM_ADDRESSES),
-            (ChainId::ARBITRUM_ONE as u64, ARBITUM_ONE_ADDRESSES),
-            (ChainId::POLYGON as u64, POLYGON_ADDRESSES),
-            (ChainId::POLYGON_MUMBAI as u64, POLYGON_ADDRESSES),
-            (ChainId::GOERLI as u64, GOERLI_ADDRESSES),
-            (ChainId::CELO as u64, CELO_ADDRESSES),
-            (ChainId::CELO_ALFAJORES as u64, CELO_ADDRESSES),
-            (ChainId::BNB as u64, BNB_ADDRESSES),
-            (ChainId::OPTIMISM_GOERLI as u64, OPTIMISM_GOERLI_ADDRESSES),
-            (ChainId::OPTIMISM_SEPOLIA as u64, OPTIMISM_SEPOLIA_ADDRESSES),
-            (ChainId::ARBITRUM_GOERLI as u64, ARBITRUM_GOERLI_ADDRESSES),
-            (ChainId::ARBITRUM_SEPOLIA as u64, ARBITRUM_SEPOLIA_ADDRESSES),
-            (ChainId::SEPOLIA as u64, SEPOLIA_ADDRESSES),
-            (ChainId::AVALANCHE as u64, AVALANCHE_ADDRESSES),
-            (ChainId::BASE as u64, BASE_ADDRESSES),
-            (ChainId::BASE_GOERLI as u64, BASE_GOERLI_ADDRESSES),
-            (ChainId::BASE_SEPOLIA as u64, BASE_SEPOLIA_ADDRESSES),
-            (ChainId::ZORA as u64, ZORA_ADDRESSES),
-            (ChainId::ZORA_SEPOLIA as u64, ZORA_SEPOLIA_ADDRESSES),
-            (ChainId::ROOTSTOCK as u64, ROOTSTOCK_ADDRESSES),
-            (ChainId::BLAST as u64, BLAST_ADDRESSES),
-            (ChainId::ZKSYNC as u64, ZKSYNC_ADDRESSES),
-            (ChainId::WORLDCHAIN as u64, WORLDCHAIN_ADDRESSES),
-            (ChainId::UNICHAIN_SEPOLIA as u64, UNICHAIN_SEPOLIA_ADDRESSES),
-            (ChainId::UNICHAIN as u64, UNICHAIN_ADDRESSES),
-            (ChainId::MONAD_TESTNET as u64, MONAD_TESTNET_ADDRESSES),
-        ])
-    };
+/// A map of chain IDs to their corresponding Uniswap contract addresses.
+///
+/// This map is used to look up the addresses of various Uniswap contracts
+/// for a given network. The keys in the map are the network IDs, and the values
+/// are the corresponding contract addresses.
+pub const CHAIN_TO_ADDRESSES_MAP: ChainMap<ChainAddresses> = ChainMap::new(&[
+    (ChainId::MAINNET as u64, MAINNET_ADDRESSES),
+    (ChainId::OPTIMISM as u64, OPTIMISM_ADDRESSES),
+    (ChainId::ARBITRUM_ONE as u64, ARBITUM_ONE_ADDRESSES),
+    (ChainId::POLYGON as u64, POLYGON_ADDRESSES),
+    (ChainId::POLYGON_MUMBAI as u64, POLYGON_ADDRESSES),
+    (ChainId::GOERLI as u64, GOERLI_ADDRESSES),
+    (ChainId::CELO as u64, CELO_ADDRESSES),
+    (ChainId::CELO_ALFAJORES as u64, CELO_ADDRESSES),
+    (ChainId::BNB as u64, BNB_ADDRESSES),
+    (ChainId::OPTIMISM_GOERLI as u64, OPTIMISM_GOERLI_ADDRESSES),
+    (ChainId::OPTIMISM_SEPOLIA as u64, OPTIMISM_SEPOLIA_ADDRESSES),
+    (ChainId::ARBITRUM_GOERLI as u64, ARBITRUM_GOERLI_ADDRESSES),
+    (ChainId::ARBITRUM_SEPOLIA as u64, ARBITRUM_SEPOLIA_ADDRESSES),
+    (ChainId::SEPOLIA as u64, SEPOLIA_ADDRESSES),
+    (ChainId::AVALANCHE as u64, AVALANCHE_ADDRESSES),
+    (ChainId::BASE as u64, BASE_ADDRESSES),
+    (ChainId::BASE_GOERLI as u64, BASE_GOERLI_ADDRESSES),
+    (ChainId::BASE_SEPOLIA as u64, BASE_SEPOLIA_ADDRESSES),
+    (ChainId::ZORA as u64, ZORA_ADDRESSES),
+    (ChainId::ZORA_SEPOLIA as u64, ZORA_SEPOLIA_ADDRESSES),
+    (ChainId::ROOTSTOCK as u64, ROOTSTOCK_ADDRESSES),
+    (ChainId::BLAST as u64, BLAST_ADDRESSES),
+    (ChainId::ZKSYNC as u64, ZKSYNC_ADDRESSES),
+    (ChainId::WORLDCHAIN as u64, WORLDCHAIN_ADDRESSES),
+    (ChainId::UNICHAIN_SEPOLIA as u64, UNICHAIN_SEPOLIA_ADDRESSES),
+    (ChainId::UNICHAIN as u64, UNICHAIN_ADDRESSES),
+    (ChainId::MONAD_TESTNET as u64, MONAD_TESTNET_ADDRESSES),
+]);
+
+/// A contract of [`ChainAddresses`] or of the v2 tables, e.g. to look up its [`Deployment`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ContractKind {
+    V3CoreFactory,
+    Multicall,
+    Quoter,
+    QuoterV2,
+    V3Migrator,
+    NonfungiblePositionManager,
+    TickLens,
+    SwapRouter02,
+    MixedRouteQuoterV1,
+    MixedRouteQuoterV2,
+    V4PoolManager,
+    V4PositionManager,
+    V4StateView,
+    V4Quoter,
+    V2Factory,
+    V2Router,
 }
 
-lazy_static! {
-    pub static ref V3_CORE_FACTORY_ADDRESSES: AddressMap =
-        AddressMap::from_iter(SUPPORTED_CHAINS.map(|chain_id| {
-            (
-                chain_id as u64,
-                CHAIN_TO_ADDRESSES_MAP[&(chain_id as u64)].v3_core_factory,
-            )
-        }));
+impl ContractKind {
+    /// All contracts, starting with those of [`ChainAddresses::CONTRACT_NAMES`] in its order.
+    pub const ALL: [Self; 16] = [
+        Self::V3CoreFactory,
+        Self::Multicall,
+        Self::Quoter,
+        Self::QuoterV2,
+        Self::V3Migrator,
+        Self::NonfungiblePositionManager,
+        Self::TickLens,
+        Self::SwapRouter02,
+        Self::MixedRouteQuoterV1,
+        Self::MixedRouteQuoterV2,
+        Self::V4PoolManager,
+        Self::V4PositionManager,
+        Self::V4StateView,
+        Self::V4Quoter,
+        Self::V2Factory,
+        Self::V2Router,
+    ];
+
+    /// Returns the name of the contract, e.g. `"v3_core_factory"`, as in
+    /// [`ChainAddresses::CONTRACT_NAMES`].
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::V3CoreFactory => "v3_core_factory",
+            Self::Multicall => "multicall",
+            Self::Quoter => "quoter",
+            Self::QuoterV2 => "quoter_v2",
+            Self::V3Migrator => "v3_migrator",
+            Self::NonfungiblePositionManager => "nonfungible_position_manager",
+            Self::TickLens => "tick_lens",
+            Self::SwapRouter02 => "swap_router02",
+            Self::MixedRouteQuoterV1 => "mixed_route_quoter_v1",
+            Self::MixedRouteQuoterV2 => "mixed_route_quoter_v2",
+            Self::V4PoolManager => "v4_pool_manager",
+            Self::V4PositionManager => "v4_position_manager",
+            Self::V4StateView => "v4_state_view",
+            Self::V4Quoter => "v4_quoter",
+            Self::V2Factory => "v2_factory",
+            Self::V2Router => "v2_router",
+        }
+    }
+
+    /// Returns the address of the contract on the chain, if it is deployed.
+    #[inline]
+    #[must_use]
+    pub const fn address(self, chain_id: u64) -> Option<Address> {
+        let address = match self {
+            Self::V2Factory => V2_FACTORY_ADDRESSES.get(&chain_id),
+            Self::V2Router => V2_ROUTER_ADDRESSES.get(&chain_id),
+            _ => {
+                return match CHAIN_TO_ADDRESSES_MAP.get(&chain_id) {
+                    Some(addresses) => addresses.get(self),
+                    None => None,
+                }
+            }
+        };
+        match address {
+            Some(address) => Some(*address),
+            None => None,
+        }
+    }
 }
 
-lazy_static! {
-    pub static ref V3_MIGRATOR_ADDRESSES: AddressMap =
-        AddressMap::from_iter(SUPPORTED_CHAINS.into_iter().filter_map(|chain_id| {
-            CHAIN_TO_ADDRESSES_MAP[&(chain_id as u64)]
-                .v3_migrator
-                .map(|address| (chain_id as u64, address))
-        }));
+impl ChainAddresses {
+    /// The names of the contracts returned by [`ChainAddresses::contracts`].
+    pub const CONTRACT_NAMES: [&'static str; 14] = [
+        "v3_core_factory",
+        "multicall",
+        "quoter",
+        "quoter_v2",
+        "v3_migrator",
+        "nonfungible_position_manager",
+        "tick_lens",
+        "swap_router02",
+        "mixed_route_quoter_v1",
+        "mixed_route_quoter_v2",
+        "v4_pool_manager",
+        "v4_position_manager",
+        "v4_state_view",
+        "v4_quoter",
+    ];
+
+    /// Returns the address of every contract in the order of [`ChainAddresses::CONTRACT_NAMES`],
+    /// or `None` if it is not deployed.
+    ///
+    /// Unlike [`SWAP_ROUTER_02_ADDRESSES`], `swap_router02` is `None` on the chains that use the
+    /// default router address.
+    #[inline]
+    #[must_use]
+    pub const fn contracts(&self) -> [Option<Address>; 14] {
+        [
+            Some(self.v3_core_factory),
+            Some(self.multicall),
+            Some(self.quoter),
+            Some(self.quoter_v2),
+            self.v3_migrator,
+            Some(self.nonfungible_position_manager),
+            self.tick_lens,
+            self.swap_router02,
+            self.mixed_route_quoter_v1,
+            self.mixed_route_quoter_v2,
+            self.v4_pool_manager,
+            self.v4_position_manager,
+            self.v4_state_view,
+            self.v4_quoter,
+        ]
+    }
+
+    #[inline]
+    const fn get(&self, contract: ContractKind) -> Option<Address> {
+        match contract {
+            ContractKind::V3CoreFactory => Some(self.v3_core_factory),
+            ContractKind::Multicall => Some(self.multicall),
+            ContractKind::Quoter => Some(self.quoter),
+            ContractKind::QuoterV2 => Some(self.quoter_v2),
+            ContractKind::V3Migrator => self.v3_migrator,
+            ContractKind::NonfungiblePositionManager => Some(self.nonfungible_position_manager),
+            ContractKind::TickLens => self.tick_lens,
+            ContractKind::SwapRouter02 => match self.swap_router02 {
+                Some(address) => Some(address),
+                None => Some(address!("0x68b3465833fb72A70ecDF485E0e4C7bD8665Fc45")),
+            },
+            ContractKind::MixedRouteQuoterV1 => self.mixed_route_quoter_v1,
+            ContractKind::MixedRouteQuoterV2 => self.mixed_route_quoter_v2,
+            ContractKind::V4PoolManager => self.v4_pool_manager,
+            ContractKind::V4PositionManager => self.v4_position_manager,
+            ContractKind::V4StateView => self.v4_state_view,
+            ContractKind::V4Quoter => self.v4_quoter,
+            // the v2 contracts have their own tables
+            ContractKind::V2Factory | ContractKind::V2Router => None,
+        }
+    }
 }
 
-lazy_static! {
-    pub static ref MULTICALL_ADDRESSES: AddressMap =
-        AddressMap::from_iter(SUPPORTED_CHAINS.map(|chain_id| {
-            (
-                chain_id as u64,
-                CHAIN_TO_ADDRESSES_MAP[&(chain_id as u64)].multicall,
-            )
-        }));
+/// Returns the number of chains in [`CHAIN_TO_ADDRESSES_MAP`] on which the contract is deployed.
+#[inline]
+const fn deployment_count(contract: ContractKind) -> usize {
+    let entries = CHAIN_TO_ADDRESSES_MAP.entries();
+    let mut count = 0;
+    let mut i = 0;
+    while i < entries.len() {
+        if entries[i].1.get(contract).is_some() {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
 }
 
-lazy_static! {
-    /// The oldest V0 governance address
-    pub static ref GOVERNANCE_ALPHA_V0_ADDRESSES: AddressMap =
-        construct_same_address_map(address!("0x5e4be8Bc9637f0EAA1A755019e06A68ce081D58F"), &[]);
+/// Returns the entries of the map of chain IDs to the address of the contract, where `N` is its
+/// [`deployment_count`].
+#[inline]
+const fn deployment_entries<const N: usize>(contract: ContractKind) -> [(u64, Address); N] {
+    let entries = CHAIN_TO_ADDRESSES_MAP.entries();
+    let mut deployments = [(0, Address::ZERO); N];
+    let mut count = 0;
+    let mut i = 0;
+    while i < entries.len() {
+        if let Some(address) = entries[i].1.get(contract) {
+            deployments[count] = (entries[i].0, address);
+            count += 1;
+        }
+        i += 1;
+    }
+    deployments
 }
 
-lazy_static! {
-    /// The older V1 governance address
-    pub static ref GOVERNANCE_ALPHA_V1_ADDRESSES: AddressMap = AddressMap::from_iter([(
-        ChainId::MAINNET as u64,
-        address!("0xC4e172459f1E7939D522503B81AFAaC1014CE6F6")
-    )]);
+/// Builds the [`AddressMap`] of a contract from [`CHAIN_TO_ADDRESSES_MAP`].
+macro_rules! deployments {
+    ($contract:expr) => {
+        AddressMap::new(&deployment_entries::<{ deployment_count($contract) }>(
+            $contract,
+        ))
+    };
 }
 
-lazy_static! {
-    /// The latest governor bravo that is currently admin of timelock
-    pub static ref GOVERNANCE_BRAVO_ADDRESSES: AddressMap = AddressMap::from_iter([(
+pub const V3_CORE_FACTORY_ADDRESSES: AddressMap = deployments!(ContractKind::V3CoreFactory);
+
+pub const V3_MIGRATOR_ADDRESSES: AddressMap = deployments!(ContractKind::V3Migrator);
+
+pub const MULTICALL_ADDRESSES: AddressMap = deployments!(ContractKind::Multicall);
+
+/// The oldest V0 governance address
+pub const GOVERNANCE_ALPHA_V0_ADDRESSES: AddressMap = AddressMap::new(&same_address_entries(
+    address!("0x5e4be8Bc9637f0EAA1A755019e06A68ce081D58F"),
+    DEFAULT_NETWORKS,
+));
+
+/// The older V1 governance address
+pub const GOVERNANCE_ALPHA_V1_ADDRESSES: AddressMap = AddressMap::new(&[(
+    ChainId::MAINNET as u64,
+    address!("0xC4e172459f1E7939D522503B81AFAaC1014CE6F6"),
+)]);
+
+/// The latest governor bravo that is currently admin of timelock
+pub const GOVERNANCE_BRAVO_ADDRESSES: AddressMap = AddressMap::new(&[(
+    ChainId::MAINNET as u64,
+    address!("0x408ED6354d4973f66138C91495F2f2FCbd8724C3"),
+)]);
+
+pub const TIMELOCK_ADDRESSES: AddressMap = AddressMap::new(&same_address_entries(
+    address!("0x1a9C8182C09F50C8318d769245beA52c32BE35BC"),
+    DEFAULT_NETWORKS,
+));
+
+pub const MERKLE_DISTRIBUTOR_ADDRESS: AddressMap = AddressMap::new(&[(
+    ChainId::MAINNET as u64,
+    address!("0x090D4613473dEE047c3f2706764f49E0821D256e"),
+)]);
+
+pub const ARGENT_WALLET_DETECTOR_ADDRESS: AddressMap = AddressMap::new(&[(
+    ChainId::MAINNET as u64,
+    address!("0xeca4B0bDBf7c55E9b7925919d03CbF8Dc82537E8"),
+)]);
+
+pub const QUOTER_ADDRESSES: AddressMap = deployments!(ContractKind::Quoter);
+
+pub const QUOTER_V2_ADDRESSES: AddressMap = deployments!(ContractKind::QuoterV2);
+
+pub const NONFUNGIBLE_POSITION_MANAGER_ADDRESSES: AddressMap =
+    deployments!(ContractKind::NonfungiblePositionManager);
+
+pub const ENS_REGISTRAR_ADDRESSES: AddressMap = AddressMap::new(&same_address_entries(
+    address!("0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e"),
+    DEFAULT_NETWORKS,
+));
+
+pub const SOCKS_CONTROLLER_ADDRESSES: AddressMap = AddressMap::new(&[(
+    ChainId::MAINNET as u64,
+    address!("0x65770b5283117639760beA3F867b69b3697a91dd"),
+)]);
+
+pub const TICK_LENS_ADDRESSES: AddressMap = deployments!(ContractKind::TickLens);
+
+pub const MIXED_ROUTE_QUOTER_V1_ADDRESSES: AddressMap =
+    deployments!(ContractKind::MixedRouteQuoterV1);
+
+pub const SWAP_ROUTER_02_ADDRESSES: AddressMap = deployments!(ContractKind::SwapRouter02);
+
+/// The Universal Router 2.0, which routes through v2, v3 and v4 pools.
+pub const UNIVERSAL_ROUTER_ADDRESSES: AddressMap = AddressMap::new(&[
+    (
         ChainId::MAINNET as u64,
-        address!("0x408ED6354d4973f66138C91495F2f2FCbd8724C3")
-    )]);
+        address!("0x66a9893cc07d91d95644aedd05d03f95e1dba8af"),
+    ),
+    (
+        ChainId::OPTIMISM as u64,
+        address!("0x851116d9223fabed8e56c0e6b8ad0c31d98b3507"),
+    ),
+    (
+        ChainId::ARBITRUM_ONE as u64,
+        address!("0xa51afafe0263b40edaef0df8781ea9aa03e381a3"),
+    ),
+    (
+        ChainId::POLYGON as u64,
+        address!("0x1095692a6237d83c6a72f3f5efedb9a670c49223"),
+    ),
+    (
+        ChainId::BNB as u64,
+        address!("0x1906c1d672b88cd1b9ac7593301ca990f94eae07"),
+    ),
+    (
+        ChainId::AVALANCHE as u64,
+        address!("0x94b75331ae8d42c1b61065089b7d48fe14aa73b7"),
+    ),
+    (
+        ChainId::BASE as u64,
+        address!("0x6ff5693b99212da76ad316178a184ab56d299b43"),
+    ),
+    (
+        ChainId::ZORA as u64,
+        address!("0x3315ef7ca28db74abadc6c44570efdf06b04b020"),
+    ),
+    (
+        ChainId::BLAST as u64,
+        address!("0xeabbcb3e8e415306207ef514f660a3f820025be3"),
+    ),
+    (
+        ChainId::WORLDCHAIN as u64,
+        address!("0x8ac7bee993bb44dab564ea4bc9ea67bf9eb5e743"),
+    ),
+    (
+        ChainId::UNICHAIN as u64,
+        address!("0xef740bf23acae26f6492b10de645d6b98dc8eaf3"),
+    ),
+    (
+        ChainId::SEPOLIA as u64,
+        address!("0x3a9d48ab9751398bbfa63ad67599bb04e4bdf98b"),
+    ),
+]);
+
+/// Permit2 is deployed at the same address on every chain.
+pub const PERMIT2_ADDRESS: Address = address!("0x000000000022D473030F116dDEE9F6B43aC78BA3");
+
+pub const PERMIT2_ADDRESSES: AddressMap = AddressMap::new(&same_address_entries(
+    PERMIT2_ADDRESS,
+    [
+        ChainId::MAINNET,
+        ChainId::GOERLI,
+        ChainId::SEPOLIA,
+        ChainId::OPTIMISM,
+        ChainId::OPTIMISM_SEPOLIA,
+        ChainId::ARBITRUM_ONE,
+        ChainId::ARBITRUM_SEPOLIA,
+        ChainId::POLYGON,
+        ChainId::CELO,
+        ChainId::BNB,
+        ChainId::AVALANCHE,
+        ChainId::BASE,
+        ChainId::BASE_SEPOLIA,
+        ChainId::ZORA,
+        ChainId::BLAST,
+        ChainId::WORLDCHAIN,
+        ChainId::UNICHAIN_SEPOLIA,
+        ChainId::UNICHAIN,
+    ],
+));
+
+/// A protocol version or router that a chain can have a deployment of.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Protocol {
+    V2,
+    V3,
+    V4,
+    SwapRouter02,
+    UniversalRouter,
+    Permit2,
 }
 
-lazy_static! {
-    pub static ref TIMELOCK_ADDRESSES: AddressMap =
-        construct_same_address_map(address!("0x1a9C8182C09F50C8318d769245beA52c32BE35BC"), &[]);
+impl Protocol {
+    /// All protocols, in the order of their bits in [`Protocols`].
+    pub const ALL: [Self; 6] = [
+        Self::V2,
+        Self::V3,
+        Self::V4,
+        Self::SwapRouter02,
+        Self::UniversalRouter,
+        Self::Permit2,
+    ];
+
+    /// Returns whether the protocol is deployed on the chain, or `None` if this is unknown.
+    ///
+    /// The tables of the Universal Router and Permit2 only list the chains they are known to be
+    /// deployed on, so their absence from a chain is unknown rather than `Some(false)`.
+    #[inline]
+    #[must_use]
+    pub const fn is_deployed(self, chain_id: u64) -> Option<bool> {
+        if self.address(chain_id).is_some() {
+            return Some(true);
+        }
+        match self {
+            Self::UniversalRouter | Self::Permit2 => None,
+            Self::V2 | Self::V3 | Self::V4 | Self::SwapRouter02 => Some(false),
+        }
+    }
+
+    /// Returns the address the protocol is entered through on the chain: the factory of v2 and
+    /// v3, the pool manager of v4, or the router.
+    #[inline]
+    #[must_use]
+    pub const fn address(self, chain_id: u64) -> Option<Address> {
+        let map = match self {
+            Self::V2 => V2_FACTORY_ADDRESSES,
+            Self::V3 => V3_CORE_FACTORY_ADDRESSES,
+            Self::V4 => {
+                return match CHAIN_TO_ADDRESSES_MAP.get(&chain_id) {
+                    Some(addresses) => addresses.v4_pool_manager,
+                    None => None,
+                }
+            }
+            Self::SwapRouter02 => SWAP_ROUTER_02_ADDRESSES,
+            Self::UniversalRouter => UNIVERSAL_ROUTER_ADDRESSES,
+            Self::Permit2 => PERMIT2_ADDRESSES,
+        };
+        match map.get(&chain_id) {
+            Some(address) => Some(*address),
+            None => None,
+        }
+    }
 }
 
-lazy_static! {
-    pub static ref MERKLE_DISTRIBUTOR_ADDRESS: AddressMap = AddressMap::from_iter([(
-        ChainId::MAINNET as u64,
-        address!("0x090D4613473dEE047c3f2706764f49E0821D256e"),
-    )]);
+/// A set of [`Protocol`]s, i.e. a row of the support matrix.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Protocols(u8);
+
+impl Protocols {
+    /// The empty set.
+    pub const EMPTY: Self = Self(0);
+
+    /// Returns the protocols with a deployment on the chain.
+    #[inline]
+    #[must_use]
+    pub const fn on_chain(chain_id: u64) -> Self {
+        let mut protocols = Self::EMPTY;
+        let mut i = 0;
+        while i < Protocol::ALL.len() {
+            if Protocol::ALL[i].address(chain_id).is_some() {
+                protocols = protocols.with(Protocol::ALL[i]);
+            }
+            i += 1;
+        }
+        protocols
+    }
+
+    /// Returns the set with the protocol added.
+    #[inline]
+    #[must_use]
+    pub const fn with(self, protocol: Protocol) -> Self {
+        Self(self.0 | 1 << protocol as u8)
+    }
+
+    /// Returns whether the set contains the protocol.
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, protocol: Protocol) -> bool {
+        self.0 & 1 << protocol as u8 != 0
+    }
+
+    /// Returns whether the set is empty.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns an iterator over the protocols in the set.
+    #[inline]
+    pub fn iter(self) -> impl Iterator<Item = Protocol> {
+        Protocol::ALL
+            .into_iter()
+            .filter(move |protocol| self.contains(*protocol))
+    }
 }
 
-lazy_static! {
-    pub static ref ARGENT_WALLET_DETECTOR_ADDRESS: AddressMap = AddressMap::from_iter([(
-        ChainId::MAINNET as u64,
-        address!("0xeca4B0bDBf7c55E9b7925919d03CbF8Dc82537E8"),
-    )]);
+/// Returns the support matrix, i.e. the protocols on every [`ChainId`].
+#[inline]
+pub fn protocol_matrix() -> impl Iterator<Item = (ChainId, Protocols)> {
+    ChainId::ALL
+        .into_iter()
+        .map(|chain_id| (chain_id, Protocols::on_chain(chain_id as u64)))
 }
 
-lazy_static! {
-    pub static ref QUOTER_ADDRESSES: AddressMap =
-        AddressMap::from_iter(SUPPORTED_CHAINS.map(|chain_id| {
-            (
-                chain_id as u64,
-                CHAIN_TO_ADDRESSES_MAP[&(chain_id as u64)].quoter,
-            )
-        }));
+/// Returns the chains on which [`SUPPORTED_CHAINS`] disagrees with the support matrix, i.e. the
+/// supported chains without v3 and the unsupported chains with any deployment.
+///
+/// `GNOSIS` and `MOONBEAM` are consistently left out, since no table has a deployment on them.
+#[inline]
+#[must_use]
+pub fn check_supported_chains() -> Vec<ChainId> {
+    protocol_matrix()
+        .filter(|(chain_id, protocols)| {
+            if SUPPORTED_CHAINS.contains(chain_id) {
+                !protocols.contains(Protocol::V3)
+            } else {
+                !protocols.is_empty()
+            }
+        })
+        .map(|(chain_id, _)| chain_id)
+        .collect()
 }
 
-lazy_static! {
-    pub static ref QUOTER_V2_ADDRESSES: AddressMap =
-        AddressMap::from_iter(SUPPORTED_CHAINS.map(|chain_id| {
-            (
-                chain_id as u64,
-                CHAIN_TO_ADDRESSES_MAP[&(chain_id as u64)].quoter_v2,
-            )
-        }));
+/// The address maps of this module, e.g. for bindings that cannot refer to the constants directly.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum AddressMapKind {
+    Uni,
+    V2Factory,
+    V2Router,
+    V3CoreFactory,
+    V3Migrator,
+    Multicall,
+    GovernanceAlphaV0,
+    GovernanceAlphaV1,
+    GovernanceBravo,
+    Timelock,
+    MerkleDistributor,
+    ArgentWalletDetector,
+    Quoter,
+    QuoterV2,
+    NonfungiblePositionManager,
+    EnsRegistrar,
+    SocksController,
+    TickLens,
+    MixedRouteQuoterV1,
+    SwapRouter02,
 }
 
-lazy_static! {
-    pub static ref NONFUNGIBLE_POSITION_MANAGER_ADDRESSES: AddressMap =
-        AddressMap::from_iter(SUPPORTED_CHAINS.map(|chain_id| {
-            (
-                chain_id as u64,
-                CHAIN_TO_ADDRESSES_MAP[&(chain_id as u64)].nonfungible_position_manager,
-            )
-        }));
+impl AddressMapKind {
+    /// All address maps.
+    pub const ALL: [Self; 20] = [
+        Self::Uni,
+        Self::V2Factory,
+        Self::V2Router,
+        Self::V3CoreFactory,
+        Self::V3Migrator,
+        Self::Multicall,
+        Self::GovernanceAlphaV0,
+        Self::GovernanceAlphaV1,
+        Self::GovernanceBravo,
+        Self::Timelock,
+        Self::MerkleDistributor,
+        Self::ArgentWalletDetector,
+        Self::Quoter,
+        Self::QuoterV2,
+        Self::NonfungiblePositionManager,
+        Self::EnsRegistrar,
+        Self::SocksController,
+        Self::TickLens,
+        Self::MixedRouteQuoterV1,
+        Self::SwapRouter02,
+    ];
+
+    /// Returns the address map.
+    #[inline]
+    #[must_use]
+    pub const fn map(self) -> AddressMap {
+        match self {
+            Self::Uni => UNI_ADDRESSES,
+            Self::V2Factory => V2_FACTORY_ADDRESSES,
+            Self::V2Router => V2_ROUTER_ADDRESSES,
+            Self::V3CoreFactory => V3_CORE_FACTORY_ADDRESSES,
+            Self::V3Migrator => V3_MIGRATOR_ADDRESSES,
+            Self::Multicall => MULTICALL_ADDRESSES,
+            Self::GovernanceAlphaV0 => GOVERNANCE_ALPHA_V0_ADDRESSES,
+            Self::GovernanceAlphaV1 => GOVERNANCE_ALPHA_V1_ADDRESSES,
+            Self::GovernanceBravo => GOVERNANCE_BRAVO_ADDRESSES,
+            Self::Timelock => TIMELOCK_ADDRESSES,
+            Self::MerkleDistributor => MERKLE_DISTRIBUTOR_ADDRESS,
+            Self::ArgentWalletDetector => ARGENT_WALLET_DETECTOR_ADDRESS,
+            Self::Quoter => QUOTER_ADDRESSES,
+            Self::QuoterV2 => QUOTER_V2_ADDRESSES,
+            Self::NonfungiblePositionManager => NONFUNGIBLE_POSITION_MANAGER_ADDRESSES,
+            Self::EnsRegistrar => ENS_REGISTRAR_ADDRESSES,
+            Self::SocksController => SOCKS_CONTROLLER_ADDRESSES,
+            Self::TickLens => TICK_LENS_ADDRESSES,
+            Self::MixedRouteQuoterV1 => MIXED_ROUTE_QUOTER_V1_ADDRESSES,
+            Self::SwapRouter02 => SWAP_ROUTER_02_ADDRESSES,
+        }
+    }
+
+    /// Returns the name of the constant holding the address map, e.g. `"UNI_ADDRESSES"`.
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Uni => "UNI_ADDRESSES",
+            Self::V2Factory => "V2_FACTORY_ADDRESSES",
+            Self::V2Router => "V2_ROUTER_ADDRESSES",
+            Self::V3CoreFactory => "V3_CORE_FACTORY_ADDRESSES",
+            Self::V3Migrator => "V3_MIGRATOR_ADDRESSES",
+            Self::Multicall => "MULTICALL_ADDRESSES",
+            Self::GovernanceAlphaV0 => "GOVERNANCE_ALPHA_V0_ADDRESSES",
+            Self::GovernanceAlphaV1 => "GOVERNANCE_ALPHA_V1_ADDRESSES",
+            Self::GovernanceBravo => "GOVERNANCE_BRAVO_ADDRESSES",
+            Self::Timelock => "TIMELOCK_ADDRESSES",
+            Self::MerkleDistributor => "MERKLE_DISTRIBUTOR_ADDRESS",
+            Self::ArgentWalletDetector => "ARGENT_WALLET_DETECTOR_ADDRESS",
+            Self::Quoter => "QUOTER_ADDRESSES",
+            Self::QuoterV2 => "QUOTER_V2_ADDRESSES",
+            Self::NonfungiblePositionManager => "NONFUNGIBLE_POSITION_MANAGER_ADDRESSES",
+            Self::EnsRegistrar => "ENS_REGISTRAR_ADDRESSES",
+            Self::SocksController => "SOCKS_CONTROLLER_ADDRESSES",
+            Self::TickLens => "TICK_LENS_ADDRESSES",
+            Self::MixedRouteQuoterV1 => "MIXED_ROUTE_QUOTER_V1_ADDRESSES",
+            Self::SwapRouter02 => "SWAP_ROUTER_02_ADDRESSES",
+        }
+    }
 }
 
-lazy_static! {
-    pub static ref ENS_REGISTRAR_ADDRESSES: AddressMap =
-        construct_same_address_map(address!("0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e"), &[]);
+/// Where and when a contract was deployed, e.g. the block at which an indexer starts scanning.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Deployment {
+    /// The number of the block containing the deployment.
+    pub block_number: u64,
+    /// The hash of the transaction that deployed the contract, if it has been recorded.
+    pub transaction_hash: Option<B256>,
 }
 
-lazy_static! {
-    pub static ref SOCKS_CONTROLLER_ADDRESSES: AddressMap = AddressMap::from_iter([(
+/// A map of chain IDs to the [`Deployment`]s of their contracts.
+///
+/// Only the Ethereum mainnet v2 factory and router, v3 core factory and v4 pool manager are
+/// recorded, the latter without its transaction hash. For every other deployed contract
+/// [`ContractKind::deployment`] returns `None`, so it can't tell an unrecorded contract from one
+/// that isn't deployed; use [`ContractKind::address`] for that.
+pub const DEPLOYMENT_PROVENANCE: ChainMap<&'static [(ContractKind, Deployment)]> =
+    ChainMap::new(&[(
         ChainId::MAINNET as u64,
-        address!("0x65770b5283117639760beA3F867b69b3697a91dd")
+        &[
+            (
+                ContractKind::V2Factory,
+                Deployment {
+                    block_number: 10000835,
+                    transaction_hash: Some(b256!(
+                        "0xc31d7e7e85cab1d38ce1b8ac17e821ccd47dbde00f9d57f2bd8613bff9428396"
+                    )),
+                },
+            ),
+            (
+                ContractKind::V2Router,
+                Deployment {
+                    block_number: 10207858,
+                    transaction_hash: Some(b256!(
+                        "0x4fc1580e7f66c58b7c26881cce0aab9c3509afe6e507527f30566fbf8039bcd0"
+                    )),
+                },
+            ),
+            (
+                ContractKind::V3CoreFactory,
+                Deployment {
+                    block_number: 12369621,
+                    transaction_hash: Some(b256!(
+                        "0x1e20cd6d47d7021ae7e437792823517eeadd835df09dde17ab45afd7a5df4603"
+                    )),
+                },
+            ),
+            (
+                ContractKind::V4PoolManager,
+                Deployment {
+                    block_number: 21688329,
+                    transaction_hash: None,
+                },
+            ),
+        ],
     )]);
-}
 
-lazy_static! {
-    pub static ref TICK_LENS_ADDRESSES: AddressMap =
-        AddressMap::from_iter(SUPPORTED_CHAINS.into_iter().filter_map(|chain_id| {
-            CHAIN_TO_ADDRESSES_MAP[&(chain_id as u64)]
-                .tick_lens
-                .map(|address| (chain_id as u64, address))
-        }));
-}
-
-lazy_static! {
-    pub static ref MIXED_ROUTE_QUOTER_V1_ADDRESSES: AddressMap =
-        AddressMap::from_iter(SUPPORTED_CHAINS.into_iter().filter_map(|chain_id| {
-            CHAIN_TO_ADDRESSES_MAP[&(chain_id as u64)]
-                .mixed_route_quoter_v1
-                .map(|address| (chain_id as u64, address))
-        }));
+impl ContractKind {
+    /// Returns the [`Deployment`] of the contract on the chain, if it is in
+    /// [`DEPLOYMENT_PROVENANCE`].
+    #[inline]
+    #[must_use]
+    pub const fn deployment(self, chain_id: u64) -> Option<Deployment> {
+        let Some(deployments) = DEPLOYMENT_PROVENANCE.get(&chain_id) else {
+            return None;
+        };
+        let mut i = 0;
+        while i < deployments.len() {
+            if deployments[i].0 as u8 == self as u8 {
+                return Some(deployments[i].1);
+            }
+            i += 1;
+        }
+        None
+    }
 }
 
-lazy_static! {
-    pub static ref SWAP_ROUTER_02_ADDRESSES: AddressMap =
-        AddressMap::from_iter(SUPPORTED_CHAINS.map(|chain_id| {
-            (
-                chain_id as u64,
-                CHAIN_TO_ADDRESSES_MAP[&(chain_id as u64)]
-                    .swap_router02
-                    .unwrap_or(address!("0x68b3465833fb72A70ecDF485E0e4C7bD8665Fc45")),
-            )
-        }));
+/// Returns the contracts of the chain with a known [`Deployment`].
+#[inline]
+pub fn deployments(chain_id: u64) -> impl Iterator<Item = (ContractKind, Deployment)> {
+    DEPLOYMENT_PROVENANCE
+        .get(&chain_id)
+        .copied()
+        .unwrap_or_default()
+        .iter()
+        .copied()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_chain_map_is_const() {
+        const MAINNET_QUOTER: Option<&Address> = QUOTER_ADDRESSES.get(&(ChainId::MAINNET as u64));
+        assert_eq!(
+            MAINNET_QUOTER,
+            Some(&address!("0xb27308f9F90D607463bb33eA1BeBb41C27CE5AB6"))
+        );
+        assert!(!QUOTER_ADDRESSES.contains_key(&(ChainId::GNOSIS as u64)));
+        assert_eq!(QUOTER_ADDRESSES.get(&(ChainId::GNOSIS as u64)), None);
+    }
+
+    #[test]
+    fn test_chain_map_keys_are_unique() {
+        for map in [
+            UNI_ADDRESSES,
+            V2_FACTORY_ADDRESSES,
+            V2_ROUTER_ADDRESSES,
+            V3_CORE_FACTORY_ADDRESSES,
+            TICK_LENS_ADDRESSES,
+            SWAP_ROUTER_02_ADDRESSES,
+        ] {
+            for (i, chain_id) in map.keys().enumerate() {
+                assert!(!map.keys().skip(i + 1).any(|other| other == chain_id));
+            }
+        }
+    }
+
+    #[test]
+    fn test_deployments_cover_supported_chains() {
+        assert_eq!(CHAIN_TO_ADDRESSES_MAP.len(), SUPPORTED_CHAINS.len());
+        for chain_id in SUPPORTED_CHAINS {
+            let chain_id = chain_id as u64;
+            assert_eq!(
+                V3_CORE_FACTORY_ADDRESSES[&chain_id],
+                CHAIN_TO_ADDRESSES_MAP[&chain_id].v3_core_factory
+            );
+            assert_eq!(
+                TICK_LENS_ADDRESSES.get(&chain_id).copied(),
+                CHAIN_TO_ADDRESSES_MAP[&chain_id].tick_lens
+            );
+        }
+        assert_eq!(UNI_ADDRESSES.len(), 7);
+        assert_eq!(
+            V3_MIGRATOR_ADDRESSES.len(),
+            CHAIN_TO_ADDRESSES_MAP
+                .values()
+                .filter(|addresses| addresses.v3_migrator.is_some())
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_protocol_matrix() {
+        let mainnet = Protocols::on_chain(ChainId::MAINNET as u64);
+        assert_eq!(mainnet.iter().collect::<Vec<_>>(), Protocol::ALL);
+        assert_eq!(
+            Protocol::UniversalRouter.address(ChainId::MAINNET as u64),
+            Some(address!("0x66a9893cC07D91D95644AEDD05D03f95e1dBA8Af"))
+        );
+        assert_eq!(
+            Protocol::Permit2.address(ChainId::BASE as u64),
+            Some(PERMIT2_ADDRESS)
+        );
+        assert_eq!(Protocol::V4.is_deployed(ChainId::CELO as u64), Some(false));
+        assert_eq!(
+            Protocol::UniversalRouter.is_deployed(ChainId::CELO as u64),
+            None
+        );
+        assert_eq!(
+            Protocol::Permit2.is_deployed(ChainId::CELO as u64),
+            Some(true)
+        );
+        let celo = Protocols::on_chain(ChainId::CELO as u64);
+        assert!(celo.contains(Protocol::V2) && !celo.contains(Protocol::V4));
+        assert!(Protocols::on_chain(ChainId::GNOSIS as u64).is_empty());
+        assert_eq!(
+            Protocol::V4.address(ChainId::MAINNET as u64),
+            CHAIN_TO_ADDRESSES_MAP[&(ChainId::MAINNET as u64)].v4_pool_manager
+        );
+        for (chain_id, protocols) in protocol_matrix() {
+            assert_eq!(
+                protocols.contains(Protocol::V3),
+                CHAIN_TO_ADDRESSES_MAP.contains_key(&(chain_id as u64))
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_supported_chains() {
+        assert_eq!(check_supported_chains(), []);
+    }
+
+    #[test]
+    fn test_contract_kinds() {
+        for (kind, name) in ContractKind::ALL
+            .into_iter()
+            .zip(ChainAddresses::CONTRACT_NAMES)
+        {
+            assert_eq!(kind.name(), name);
+        }
+        for (chain_id, addresses) in CHAIN_TO_ADDRESSES_MAP.iter() {
+            for (kind, address) in ContractKind::ALL.into_iter().zip(addresses.contracts()) {
+                if kind != ContractKind::SwapRouter02 {
+                    assert_eq!(kind.address(*chain_id), address);
+                }
+            }
+        }
+        assert_eq!(
+            ContractKind::V2Router.address(ChainId::MAINNET as u64),
+            Some(V2_ROUTER_ADDRESS)
+        );
+    }
+
+    #[test]
+    #[ignore = "the provenance of most mainnet contracts, and the v4 pool manager transaction, is \
+                not recorded yet"]
+    fn test_mainnet_deployments_are_recorded() {
+        let chain_ids: alloc::collections::BTreeSet<_> = CHAIN_TO_ADDRESSES_MAP
+            .keys()
+            .chain(V2_FACTORY_ADDRESSES.keys())
+            .chain(V2_ROUTER_ADDRESSES.keys())
+            .collect();
+        let missing: Vec<_> = chain_ids
+            .into_iter()
+            .filter(|chain_id| ChainId::from_id(**chain_id).is_some_and(|id| !id.is_testnet()))
+            .flat_map(|chain_id| {
+                ContractKind::ALL
+                    .into_iter()
+                    .filter(|kind| {
+                        kind.address(*chain_id).is_some()
+                            && kind
+                                .deployment(*chain_id)
+                                .is_none_or(|deployment| deployment.transaction_hash.is_none())
+                    })
+                    .map(move |kind| (*chain_id, kind.name()))
+            })
+            .collect();
+        assert_eq!(missing, []);
+    }
+
+    #[test]
+    fn test_deployment_provenance() {
+        for (chain_id, entries) in DEPLOYMENT_PROVENANCE.iter() {
+            for (i, (kind, deployment)) in entries.iter().enumerate() {
+                // every deployment belongs to an address of the tables
+                assert!(kind.address(*chain_id).is_some());
+                assert!(deployment.block_number > 0);
+                assert!(!deployment
+                    .transaction_hash
+                    .is_some_and(|hash| hash.is_zero()));
+                assert!(!entries[i + 1..].iter().any(|(other, _)| other == kind));
+                assert_eq!(kind.deployment(*chain_id), Some(*deployment));
+            }
+            assert_eq!(deployments(*chain_id).count(), entries.len());
+        }
+        let mainnet = ChainId::MAINNET as u64;
+        assert_eq!(
+            ContractKind::V3CoreFactory.deployment(mainnet),
+            Some(Deployment {
+                block_number: 12369621,
+                transaction_hash: Some(b256!(
+                    "0x1e20cd6d47d7021ae7e437792823517eeadd835df09dde17ab45afd7a5df4603"
+                )),
+            })
+        );
+        assert_eq!(
+            ContractKind::V2Factory
+                .deployment(mainnet)
+                .map(|deployment| deployment.block_number),
+            Some(10000835)
+        );
+        assert_eq!(
+            ContractKind::V4PoolManager
+                .deployment(mainnet)
+                .map(|deployment| deployment.block_number),
+            Some(21688329)
+        );
+        assert_eq!(ContractKind::V2Factory.deployment(0), None);
+        assert_eq!(deployments(0).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "chain ID not in map")]
+    fn test_chain_map_index_missing() {
+        let _ = SWAP_ROUTER_02_ADDRESSES[&(ChainId::GNOSIS as u64)];
+    }
+
     #[test]
     fn test_swap_router_02_addresses_base() {
         let address = SWAP_ROUTER_02_ADDRESSES[&(ChainId::BASE as u64)];
@@ -0,0 +1,31 @@
+//! Prints the address and token tables of the SDK as JSON or TOML.
+//!
+//! Usage: `uniswap-addresses [json|toml]`, where the format defaults to JSON.
+
+use std::{
+    env,
+    io::{self, Write},
+    process::ExitCode,
+};
+use uniswap_sdk_core::export::Document;
+
+fn main() -> ExitCode {
+    let document = Document::sdk();
+    let output = match env::args().nth(1).as_deref() {
+        None | Some("json") => document.to_json() + "\n",
+        Some("toml") => document.to_toml(),
+        Some(format) => {
+            eprintln!("unknown format {format:?}, expected \"json\" or \"toml\"");
+            return ExitCode::FAILURE;
+        }
+    };
+    match io::stdout().lock().write_all(output.as_bytes()) {
+        Ok(()) => ExitCode::SUCCESS,
+        // e.g. the output is piped into `head`
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("failed to write the document: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,929 @@
+//! C bindings with opaque handles, described by `include/uniswap_sdk_core.h`.
+//!
+//! Every fallible function returns a [`UscErrorCode`] and writes its result through an out
+//! pointer. On failure, [`usc_last_error_message`] describes the error of the calling thread.
+//! Handles and strings returned by the library are owned by the caller, who releases them with
+//! the matching `usc_*_free` function.
+//!
+//! The header is generated with `cbindgen --config cbindgen.toml --output
+//! include/uniswap_sdk_core.h`.
+
+// exported functions ignore `#[inline]`, so it is not required here
+#![allow(clippy::missing_inline_in_public_items)]
+
+use crate::prelude::*;
+use core::{ffi::c_char, ptr};
+use std::{
+    cell::RefCell,
+    ffi::{CStr, CString},
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+/// The result of a call, where everything but [`UscErrorCode::Ok`] is an error.
+///
+/// The codes below 100 are the [`Error::code`]s of the SDK errors.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UscErrorCode {
+    Ok = 0,
+    ChainIdMismatch = 1,
+    EqualAddresses = 2,
+    UintOverflow = 3,
+    CurrencyMismatch = 4,
+    Invalid = 5,
+    ZeroChainId = 6,
+    ZeroAddress = 7,
+    InvalidAddress = 8,
+    ChecksumMismatch = 9,
+    FeeBpsOutOfRange = 10,
+    DecimalsOutOfRange = 11,
+    ZeroQuote = 12,
+    DivisionByZero = 13,
+    Parse = 14,
+    UnsupportedChainId = 15,
+    MissingWrappedNative = 16,
+    ZeroSignificantDigits = 17,
+    Negative = 18,
+    Empty = 19,
+    InsufficientBalance = 20,
+    OutOfRange = 21,
+    InvalidPath = 22,
+    ZeroPeriods = 23,
+    UnknownFeeAmount = 24,
+    DuplicateCurrency = 25,
+    /// A required pointer argument is null.
+    NullPointer = 100,
+    /// A string argument is not valid UTF-8.
+    InvalidArgument = 101,
+    /// The library panicked.
+    Panic = 102,
+}
+
+impl From<&Error> for UscErrorCode {
+    #[inline]
+    fn from(error: &Error) -> Self {
+        match error {
+            Error::ChainIdMismatch(..) => Self::ChainIdMismatch,
+            Error::EqualAddresses => Self::EqualAddresses,
+            Error::UintOverflow => Self::UintOverflow,
+            Error::CurrencyMismatch => Self::CurrencyMismatch,
+            Error::Invalid(_) => Self::Invalid,
+            Error::ZeroChainId => Self::ZeroChainId,
+            Error::ZeroAddress => Self::ZeroAddress,
+            Error::InvalidAddress => Self::InvalidAddress,
+            Error::ChecksumMismatch(_) => Self::ChecksumMismatch,
+            Error::FeeBpsOutOfRange(_) => Self::FeeBpsOutOfRange,
+            Error::DecimalsOutOfRange { .. } => Self::DecimalsOutOfRange,
+            Error::ZeroQuote => Self::ZeroQuote,
+            Error::DivisionByZero => Self::DivisionByZero,
+            Error::Parse { .. } => Self::Parse,
+            Error::UnsupportedChainId(_) => Self::UnsupportedChainId,
+            Error::MissingWrappedNative(_) => Self::MissingWrappedNative,
+            Error::ZeroSignificantDigits => Self::ZeroSignificantDigits,
+            Error::Negative => Self::Negative,
+            Error::Empty => Self::Empty,
+            Error::InsufficientBalance { .. } => Self::InsufficientBalance,
+            Error::OutOfRange { .. } => Self::OutOfRange,
+            Error::InvalidPath => Self::InvalidPath,
+            Error::ZeroPeriods => Self::ZeroPeriods,
+            Error::UnknownFeeAmount(_) => Self::UnknownFeeAmount,
+            Error::DuplicateCurrency => Self::DuplicateCurrency,
+        }
+    }
+}
+
+/// The rounding mode of the formatting functions, which take it as a `uint32_t` and reject other
+/// values with `InvalidArgument`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UscRounding {
+    RoundDown = 0,
+    RoundHalfUp = 1,
+    RoundUp = 2,
+}
+
+impl From<UscRounding> for Rounding {
+    #[inline]
+    fn from(rounding: UscRounding) -> Self {
+        match rounding {
+            UscRounding::RoundDown => Self::RoundDown,
+            UscRounding::RoundHalfUp => Self::RoundHalfUp,
+            UscRounding::RoundUp => Self::RoundUp,
+        }
+    }
+}
+
+/// A token or the native currency of a chain.
+#[derive(Clone, Debug)]
+pub struct UscCurrency(Currency);
+
+/// An amount of a currency.
+#[derive(Clone, Debug)]
+pub struct UscCurrencyAmount(CurrencyAmount<Currency>);
+
+/// The price of a base currency in terms of a quote currency.
+#[derive(Clone, Debug)]
+pub struct UscPrice(Price<Currency, Currency>);
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// The failure of a call.
+struct Failure(UscErrorCode, String);
+
+impl From<Error> for Failure {
+    fn from(error: Error) -> Self {
+        Self((&error).into(), error.to_string())
+    }
+}
+
+impl Failure {
+    fn null_pointer() -> Self {
+        Self(UscErrorCode::NullPointer, "null pointer".to_string())
+    }
+
+    fn invalid_argument(message: &str) -> Self {
+        Self(UscErrorCode::InvalidArgument, message.to_string())
+    }
+}
+
+/// Runs the call, catching panics and recording the error message of failures.
+fn call(f: impl FnOnce() -> Result<(), Failure>) -> UscErrorCode {
+    let Failure(code, message) = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => return UscErrorCode::Ok,
+        Ok(Err(failure)) => failure,
+        Err(panic) => Failure(
+            UscErrorCode::Panic,
+            panic
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "panic".to_string()),
+        ),
+    };
+    LAST_ERROR.with(|last| *last.borrow_mut() = CString::new(message).ok());
+    code
+}
+
+/// Dereferences a handle argument.
+///
+/// # Safety
+///
+/// The pointer must be null or valid for the duration of the call.
+unsafe fn handle<'a, T>(pointer: *const T) -> Result<&'a T, Failure> {
+    pointer.as_ref().ok_or_else(Failure::null_pointer)
+}
+
+/// Reads a string argument.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a NUL-terminated string.
+unsafe fn string<'a>(pointer: *const c_char) -> Result<&'a str, Failure> {
+    if pointer.is_null() {
+        return Err(Failure::null_pointer());
+    }
+    CStr::from_ptr(pointer)
+        .to_str()
+        .map_err(|_| Failure::invalid_argument("string is not valid UTF-8"))
+}
+
+/// Reads a decimal integer argument.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a NUL-terminated string.
+unsafe fn big_int(pointer: *const c_char) -> Result<BigInt, Failure> {
+    Ok(parse_big_int(string(pointer)?)?)
+}
+
+/// Reads a non-zero decimal integer argument.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a NUL-terminated string.
+unsafe fn non_zero_big_int(pointer: *const c_char) -> Result<BigInt, Failure> {
+    let denominator = big_int(pointer)?;
+    if denominator == BigInt::ZERO {
+        return Err(Error::DivisionByZero.into());
+    }
+    Ok(denominator)
+}
+
+/// Reads a rounding mode argument, one of the [`UscRounding`] values.
+fn rounding_mode(rounding: u32) -> Result<Rounding, Failure> {
+    let rounding = match rounding {
+        0 => UscRounding::RoundDown,
+        1 => UscRounding::RoundHalfUp,
+        2 => UscRounding::RoundUp,
+        _ => return Err(Failure::invalid_argument("unknown rounding mode")),
+    };
+    Ok(rounding.into())
+}
+
+/// Writes a result through an out pointer.
+///
+/// # Safety
+///
+/// The pointer must be null or valid for writes.
+unsafe fn write<T>(out: *mut T, value: T) -> Result<(), Failure> {
+    if out.is_null() {
+        return Err(Failure::null_pointer());
+    }
+    out.write(value);
+    Ok(())
+}
+
+/// Writes a string result through an out pointer.
+///
+/// # Safety
+///
+/// The pointer must be null or valid for writes.
+unsafe fn write_string(out: *mut *mut c_char, value: String) -> Result<(), Failure> {
+    // check before allocating, as the string would leak if it could not be written
+    if out.is_null() {
+        return Err(Failure::null_pointer());
+    }
+    let value = CString::new(value).map_err(|_| Failure::invalid_argument("string has a NUL"))?;
+    write(out, value.into_raw())
+}
+
+/// Writes a new handle through an out pointer.
+///
+/// # Safety
+///
+/// The pointer must be null or valid for writes.
+unsafe fn write_handle<T>(out: *mut *mut T, value: T) -> Result<(), Failure> {
+    // check before allocating, as the handle would leak if it could not be written
+    if out.is_null() {
+        return Err(Failure::null_pointer());
+    }
+    write(out, Box::into_raw(Box::new(value)))
+}
+
+/// Returns the error message of the last failed call on this thread, or null if there is none.
+///
+/// The message is owned by the library and valid until the next failed call on this thread.
+#[no_mangle]
+pub extern "C" fn usc_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Frees a string returned by the library.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned by the library that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn usc_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Creates a token, validating its address and decimals.
+///
+/// # Safety
+///
+/// `address` must point to a NUL-terminated string, `symbol` and `name` must be null or point to
+/// NUL-terminated strings, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_token_new(
+    chain_id: u64,
+    address: *const c_char,
+    decimals: u8,
+    symbol: *const c_char,
+    name: *const c_char,
+    out: *mut *mut UscCurrency,
+) -> UscErrorCode {
+    call(|| {
+        let mut builder = Token::builder(chain_id)
+            .address_str(string(address)?)
+            .decimals(decimals);
+        if !symbol.is_null() {
+            builder = builder.symbol(string(symbol)?);
+        }
+        if !name.is_null() {
+            builder = builder.name(string(name)?);
+        }
+        let currency = UscCurrency(builder.build()?.into());
+        write_handle(out, currency)
+    })
+}
+
+/// Creates the native currency of the chain.
+///
+/// # Safety
+///
+/// `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_native_currency_new(
+    chain_id: u64,
+    out: *mut *mut UscCurrency,
+) -> UscErrorCode {
+    call(|| {
+        if chain_id == 0 {
+            return Err(Error::ZeroChainId.into());
+        }
+        let currency = UscCurrency(Ether::on_chain(chain_id).into());
+        write_handle(out, currency)
+    })
+}
+
+/// Returns the wrapped token of the native currency, or a copy of the token.
+///
+/// # Safety
+///
+/// `currency` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_currency_wrapped(
+    currency: *const UscCurrency,
+    out: *mut *mut UscCurrency,
+) -> UscErrorCode {
+    call(|| {
+        let wrapped = match &handle(currency)?.0 {
+            Currency::Token(token) => token.clone(),
+            Currency::NativeCurrency(ether) => WETH9::on_chain(ether.chain_id())
+                .ok_or(Error::MissingWrappedNative(ether.chain_id()))?,
+        };
+        write_handle(out, UscCurrency(wrapped.into()))
+    })
+}
+
+/// Writes the checksummed address of the token, or of the wrapped native token.
+///
+/// # Safety
+///
+/// `currency` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_currency_address(
+    currency: *const UscCurrency,
+    out: *mut *mut c_char,
+) -> UscErrorCode {
+    call(|| {
+        let address = handle(currency)?
+            .0
+            .to_address(NativeAddressConvention::Wrapped)?;
+        write_string(out, address.to_string())
+    })
+}
+
+/// Returns the decimals of the currency, or 0 for a null handle.
+///
+/// # Safety
+///
+/// `currency` must be null or a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn usc_currency_decimals(currency: *const UscCurrency) -> u8 {
+    currency
+        .as_ref()
+        .map_or(0, |currency| currency.0.decimals())
+}
+
+/// Frees a currency handle.
+///
+/// # Safety
+///
+/// `currency` must be null or a handle returned by the library that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn usc_currency_free(currency: *mut UscCurrency) {
+    if !currency.is_null() {
+        drop(Box::from_raw(currency));
+    }
+}
+
+/// Creates an amount from the raw amount of the smallest unit of the currency, given as a
+/// decimal string.
+///
+/// # Safety
+///
+/// `currency` must be a valid handle, `raw_amount` must point to a NUL-terminated string and
+/// `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_amount_from_raw(
+    currency: *const UscCurrency,
+    raw_amount: *const c_char,
+    out: *mut *mut UscCurrencyAmount,
+) -> UscErrorCode {
+    call(|| {
+        let amount =
+            CurrencyAmount::from_raw_amount(handle(currency)?.0.clone(), big_int(raw_amount)?)?;
+        write_handle(out, UscCurrencyAmount(amount))
+    })
+}
+
+/// Adds two amounts of the same currency.
+///
+/// # Safety
+///
+/// `a` and `b` must be valid handles and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_amount_add(
+    a: *const UscCurrencyAmount,
+    b: *const UscCurrencyAmount,
+    out: *mut *mut UscCurrencyAmount,
+) -> UscErrorCode {
+    call(|| {
+        let sum = handle(a)?.0.add(&handle(b)?.0)?;
+        write_handle(out, UscCurrencyAmount(sum))
+    })
+}
+
+/// Subtracts an amount of the same currency.
+///
+/// # Safety
+///
+/// `a` and `b` must be valid handles and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_amount_subtract(
+    a: *const UscCurrencyAmount,
+    b: *const UscCurrencyAmount,
+    out: *mut *mut UscCurrencyAmount,
+) -> UscErrorCode {
+    call(|| {
+        let difference = handle(a)?.0.subtract(&handle(b)?.0)?;
+        write_handle(out, UscCurrencyAmount(difference))
+    })
+}
+
+/// Multiplies an amount by the fraction `numerator / denominator`, given as decimal strings.
+///
+/// # Safety
+///
+/// `amount` must be a valid handle, `numerator` and `denominator` must point to NUL-terminated
+/// strings and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_amount_multiply(
+    amount: *const UscCurrencyAmount,
+    numerator: *const c_char,
+    denominator: *const c_char,
+    out: *mut *mut UscCurrencyAmount,
+) -> UscErrorCode {
+    call(|| {
+        let fraction = Fraction::new(big_int(numerator)?, non_zero_big_int(denominator)?);
+        let product = handle(amount)?.0.multiply(&fraction)?;
+        write_handle(out, UscCurrencyAmount(product))
+    })
+}
+
+/// Divides an amount by the fraction `numerator / denominator`, given as decimal strings.
+///
+/// # Safety
+///
+/// `amount` must be a valid handle, `numerator` and `denominator` must point to NUL-terminated
+/// strings and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_amount_divide(
+    amount: *const UscCurrencyAmount,
+    numerator: *const c_char,
+    denominator: *const c_char,
+    out: *mut *mut UscCurrencyAmount,
+) -> UscErrorCode {
+    call(|| {
+        let fraction = Fraction::new(big_int(numerator)?, non_zero_big_int(denominator)?);
+        let quotient = handle(amount)?.0.divide(&fraction)?;
+        write_handle(out, UscCurrencyAmount(quotient))
+    })
+}
+
+/// Writes the raw amount, rounded towards zero, as a decimal string.
+///
+/// # Safety
+///
+/// `amount` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_amount_quotient(
+    amount: *const UscCurrencyAmount,
+    out: *mut *mut c_char,
+) -> UscErrorCode {
+    call(|| write_string(out, handle(amount)?.0.quotient().to_string()))
+}
+
+/// Writes the exact amount in units of the currency.
+///
+/// # Safety
+///
+/// `amount` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_amount_to_exact(
+    amount: *const UscCurrencyAmount,
+    out: *mut *mut c_char,
+) -> UscErrorCode {
+    call(|| write_string(out, handle(amount)?.0.to_exact()))
+}
+
+/// Writes the amount in units of the currency with `significant_digits` significant digits.
+///
+/// # Safety
+///
+/// `amount` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_amount_to_significant(
+    amount: *const UscCurrencyAmount,
+    significant_digits: u8,
+    rounding: u32,
+    out: *mut *mut c_char,
+) -> UscErrorCode {
+    call(|| {
+        let formatted = handle(amount)?
+            .0
+            .to_significant(significant_digits, Some(rounding_mode(rounding)?))?;
+        write_string(out, formatted)
+    })
+}
+
+/// Writes the amount in units of the currency with `decimal_places` decimal places.
+///
+/// # Safety
+///
+/// `amount` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_amount_to_fixed(
+    amount: *const UscCurrencyAmount,
+    decimal_places: u8,
+    rounding: u32,
+    out: *mut *mut c_char,
+) -> UscErrorCode {
+    call(|| {
+        let formatted = handle(amount)?
+            .0
+            .to_fixed(decimal_places, Some(rounding_mode(rounding)?))?;
+        write_string(out, formatted)
+    })
+}
+
+/// Frees an amount handle.
+///
+/// # Safety
+///
+/// `amount` must be null or a handle returned by the library that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn usc_amount_free(amount: *mut UscCurrencyAmount) {
+    if !amount.is_null() {
+        drop(Box::from_raw(amount));
+    }
+}
+
+/// Creates the price at which `denominator` raw base units trade for `numerator` raw quote
+/// units, given as decimal strings.
+///
+/// # Safety
+///
+/// `base` and `quote` must be valid handles, `denominator` and `numerator` must point to
+/// NUL-terminated strings and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_price_new(
+    base: *const UscCurrency,
+    quote: *const UscCurrency,
+    denominator: *const c_char,
+    numerator: *const c_char,
+    out: *mut *mut UscPrice,
+) -> UscErrorCode {
+    call(|| {
+        let price = Price::new(
+            handle(base)?.0.clone(),
+            handle(quote)?.0.clone(),
+            non_zero_big_int(denominator)?,
+            big_int(numerator)?,
+        );
+        write_handle(out, UscPrice(price))
+    })
+}
+
+/// Inverts a price.
+///
+/// # Safety
+///
+/// `price` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_price_invert(
+    price: *const UscPrice,
+    out: *mut *mut UscPrice,
+) -> UscErrorCode {
+    call(|| {
+        let inverted = handle(price)?.0.invert();
+        write_handle(out, UscPrice(inverted))
+    })
+}
+
+/// Multiplies a price by another price whose base currency is the quote currency of the first.
+///
+/// # Safety
+///
+/// `a` and `b` must be valid handles and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_price_multiply(
+    a: *const UscPrice,
+    b: *const UscPrice,
+    out: *mut *mut UscPrice,
+) -> UscErrorCode {
+    call(|| {
+        let product = handle(a)?.0.multiply(&handle(b)?.0)?;
+        write_handle(out, UscPrice(product))
+    })
+}
+
+/// Returns the amount of quote currency corresponding to an amount of base currency.
+///
+/// # Safety
+///
+/// `price` and `amount` must be valid handles and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_price_quote(
+    price: *const UscPrice,
+    amount: *const UscCurrencyAmount,
+    out: *mut *mut UscCurrencyAmount,
+) -> UscErrorCode {
+    call(|| {
+        let quoted = handle(price)?.0.quote(&handle(amount)?.0)?;
+        write_handle(out, UscCurrencyAmount(quoted))
+    })
+}
+
+/// Writes the price with `significant_digits` significant digits.
+///
+/// # Safety
+///
+/// `price` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_price_to_significant(
+    price: *const UscPrice,
+    significant_digits: u8,
+    rounding: u32,
+    out: *mut *mut c_char,
+) -> UscErrorCode {
+    call(|| {
+        let formatted = handle(price)?
+            .0
+            .to_significant(significant_digits, Some(rounding_mode(rounding)?))?;
+        write_string(out, formatted)
+    })
+}
+
+/// Writes the price with `decimal_places` decimal places.
+///
+/// # Safety
+///
+/// `price` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_price_to_fixed(
+    price: *const UscPrice,
+    decimal_places: u8,
+    rounding: u32,
+    out: *mut *mut c_char,
+) -> UscErrorCode {
+    call(|| {
+        let formatted = handle(price)?
+            .0
+            .to_fixed(decimal_places, Some(rounding_mode(rounding)?));
+        write_string(out, formatted)
+    })
+}
+
+/// Frees a price handle.
+///
+/// # Safety
+///
+/// `price` must be null or a handle returned by the library that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn usc_price_free(price: *mut UscPrice) {
+    if !price.is_null() {
+        drop(Box::from_raw(price));
+    }
+}
+
+/// Writes the checksummed address of a contract on the chain.
+///
+/// `map` is the name of an address map constant, e.g. `"V3_CORE_FACTORY_ADDRESSES"`. Fails with
+/// [`UscErrorCode::Invalid`] if the map is unknown and with [`UscErrorCode::UnsupportedChainId`] if
+/// it has no address for the chain.
+///
+/// # Safety
+///
+/// `map` must point to a NUL-terminated string and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn usc_address_lookup(
+    map: *const c_char,
+    chain_id: u64,
+    out: *mut *mut c_char,
+) -> UscErrorCode {
+    call(|| {
+        let map = string(map)?;
+        let address = AddressMapKind::ALL
+            .into_iter()
+            .find(|kind| kind.name() == map)
+            .ok_or(Error::Parse {
+                what: "address map",
+                position: 0,
+            })?
+            .map()
+            .get(&chain_id)
+            .ok_or(Error::UnsupportedChainId(chain_id))?;
+        write_string(out, address.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn take_string(string: *mut c_char) -> String {
+        let value = unsafe { CStr::from_ptr(string) }
+            .to_str()
+            .unwrap()
+            .to_string();
+        unsafe { usc_string_free(string) };
+        value
+    }
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(usc_last_error_message()) }
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_amounts_and_prices() {
+        unsafe {
+            let mut usdc = ptr::null_mut();
+            assert_eq!(
+                usc_token_new(
+                    1,
+                    c"0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".as_ptr(),
+                    6,
+                    c"USDC".as_ptr(),
+                    ptr::null(),
+                    &mut usdc,
+                ),
+                UscErrorCode::Ok
+            );
+            let mut eth = ptr::null_mut();
+            assert_eq!(usc_native_currency_new(1, &mut eth), UscErrorCode::Ok);
+            assert_eq!(usc_currency_decimals(eth), 18);
+
+            let mut one_eth = ptr::null_mut();
+            assert_eq!(
+                usc_amount_from_raw(eth, c"1000000000000000000".as_ptr(), &mut one_eth),
+                UscErrorCode::Ok
+            );
+            let mut two_eth = ptr::null_mut();
+            assert_eq!(
+                usc_amount_add(one_eth, one_eth, &mut two_eth),
+                UscErrorCode::Ok
+            );
+            let mut out = ptr::null_mut();
+            assert_eq!(usc_amount_quotient(two_eth, &mut out), UscErrorCode::Ok);
+            assert_eq!(take_string(out), "2000000000000000000");
+            let mut fee = ptr::null_mut();
+            assert_eq!(
+                usc_amount_multiply(two_eth, c"3".as_ptr(), c"1000".as_ptr(), &mut fee),
+                UscErrorCode::Ok
+            );
+            assert_eq!(usc_amount_to_exact(fee, &mut out), UscErrorCode::Ok);
+            assert_eq!(take_string(out), "0.006");
+
+            let mut price = ptr::null_mut();
+            assert_eq!(
+                usc_price_new(
+                    eth,
+                    usdc,
+                    c"1000000000000000000".as_ptr(),
+                    c"2000000000".as_ptr(),
+                    &mut price
+                ),
+                UscErrorCode::Ok
+            );
+            let mut quoted = ptr::null_mut();
+            assert_eq!(
+                usc_price_quote(price, two_eth, &mut quoted),
+                UscErrorCode::Ok
+            );
+            assert_eq!(
+                usc_amount_to_fixed(quoted, 2, UscRounding::RoundDown as u32, &mut out),
+                UscErrorCode::Ok
+            );
+            assert_eq!(take_string(out), "4000.00");
+            let mut inverted = ptr::null_mut();
+            assert_eq!(usc_price_invert(price, &mut inverted), UscErrorCode::Ok);
+            assert_eq!(
+                usc_price_to_significant(inverted, 2, UscRounding::RoundHalfUp as u32, &mut out),
+                UscErrorCode::Ok
+            );
+            assert_eq!(take_string(out), "0.0005");
+
+            let mut sum = ptr::null_mut();
+            assert_eq!(
+                usc_amount_add(one_eth, quoted, &mut sum),
+                UscErrorCode::CurrencyMismatch
+            );
+            assert_eq!(last_error(), "currency values are not equal");
+            assert!(sum.is_null());
+
+            for amount in [one_eth, two_eth, fee, quoted] {
+                usc_amount_free(amount);
+            }
+            usc_price_free(price);
+            usc_price_free(inverted);
+            usc_currency_free(usdc);
+            usc_currency_free(eth);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        unsafe {
+            let mut currency = ptr::null_mut();
+            assert_eq!(
+                usc_token_new(
+                    1,
+                    c"0x1234".as_ptr(),
+                    18,
+                    ptr::null(),
+                    ptr::null(),
+                    &mut currency
+                ),
+                UscErrorCode::InvalidAddress
+            );
+            assert_eq!(
+                usc_native_currency_new(1, ptr::null_mut()),
+                UscErrorCode::NullPointer
+            );
+            assert_eq!(usc_native_currency_new(1, &mut currency), UscErrorCode::Ok);
+            let mut amount = ptr::null_mut();
+            assert_eq!(
+                usc_amount_from_raw(currency, c"1.5".as_ptr(), &mut amount),
+                UscErrorCode::Parse
+            );
+            assert_eq!(last_error(), "invalid integer at position 1");
+            assert_eq!(
+                usc_amount_from_raw(currency, c"15".as_ptr(), &mut amount),
+                UscErrorCode::Ok
+            );
+            let mut product = ptr::null_mut();
+            assert_eq!(
+                usc_amount_multiply(amount, c"1".as_ptr(), c"0".as_ptr(), &mut product),
+                UscErrorCode::DivisionByZero
+            );
+            assert_eq!(last_error(), "division by zero");
+            assert_eq!(
+                usc_amount_add(amount, amount, ptr::null_mut()),
+                UscErrorCode::NullPointer
+            );
+            let mut out = ptr::null_mut();
+            assert_eq!(
+                usc_amount_to_fixed(amount, 2, 3, &mut out),
+                UscErrorCode::InvalidArgument
+            );
+            assert_eq!(last_error(), "unknown rounding mode");
+            assert!(out.is_null());
+            usc_amount_free(amount);
+            usc_currency_free(currency);
+        }
+    }
+
+    #[test]
+    fn test_error_codes() {
+        for error in [
+            Error::ChainIdMismatch(1, 2),
+            Error::Invalid("invalid"),
+            Error::ZeroQuote,
+            Error::Parse {
+                what: "integer",
+                position: 0,
+            },
+            Error::ZeroSignificantDigits,
+            Error::Negative,
+            Error::Empty,
+            Error::InvalidPath,
+            Error::DuplicateCurrency,
+        ] {
+            assert_eq!(UscErrorCode::from(&error) as u32, error.code());
+        }
+    }
+
+    #[test]
+    fn test_address_lookup() {
+        unsafe {
+            let mut out = ptr::null_mut();
+            assert_eq!(
+                usc_address_lookup(c"V3_CORE_FACTORY_ADDRESSES".as_ptr(), 1, &mut out),
+                UscErrorCode::Ok
+            );
+            assert_eq!(
+                take_string(out),
+                "0x1F98431c8aD98523631AE4a59f267346ea31F984"
+            );
+            assert_eq!(
+                usc_address_lookup(c"GOVERNANCE_BRAVO_ADDRESSES".as_ptr(), 10, &mut out),
+                UscErrorCode::UnsupportedChainId
+            );
+            assert_eq!(last_error(), "unsupported chain ID 10");
+        }
+    }
+}
@@ -1,5 +1,8 @@
 #![allow(non_camel_case_types)]
 
+use crate::prelude::Error;
+use core::{fmt, str::FromStr};
+
 /// Represents the unique identifier for different blockchain networks supported by the Uniswap SDK.
 ///
 /// Each variant corresponds to a specific blockchain network, identified by its unique chain ID.
@@ -69,3 +72,491 @@ pub const SUPPORTED_CHAINS: [ChainId; 27] = [
     ChainId::UNICHAIN,
     ChainId::MONAD_TESTNET,
 ];
+
+/// The kind of network a chain is, by how it settles.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ChainType {
+    /// A chain that settles on its own, including sidechains.
+    L1,
+    /// A rollup built on the OP Stack.
+    OpStack,
+    /// A rollup built on Arbitrum Nitro.
+    Arbitrum,
+    /// A rollup built on the ZK Stack.
+    ZkSync,
+}
+
+impl ChainType {
+    /// Returns whether the chain is a layer 2 that settles on a parent chain.
+    #[inline]
+    #[must_use]
+    pub const fn is_l2(self) -> bool {
+        !matches!(self, Self::L1)
+    }
+}
+
+/// The metadata of a chain, as returned by [`ChainId::info`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ChainInfo {
+    /// The kebab-case name the chain is parsed from and displayed as, e.g. `"arbitrum-one"`.
+    pub slug: &'static str,
+    /// The human-readable name, e.g. `"Arbitrum One"`.
+    pub name: &'static str,
+    /// The symbol of the native currency, e.g. `"ETH"`.
+    pub native_symbol: &'static str,
+    /// Whether the chain is a testnet.
+    pub testnet: bool,
+    /// The kind of network.
+    pub chain_type: ChainType,
+    /// The chain that a layer 2 settles on, if it is a [`ChainId`].
+    pub parent: Option<ChainId>,
+    /// Whether the chain has been shut down or abandoned, e.g. the Goerli family.
+    pub deprecated: bool,
+}
+
+impl ChainInfo {
+    #[inline]
+    const fn new(
+        slug: &'static str,
+        name: &'static str,
+        native_symbol: &'static str,
+        testnet: bool,
+        chain_type: ChainType,
+        parent: Option<ChainId>,
+        deprecated: bool,
+    ) -> Self {
+        Self {
+            slug,
+            name,
+            native_symbol,
+            testnet,
+            chain_type,
+            parent,
+            deprecated,
+        }
+    }
+}
+
+impl ChainId {
+    /// All chain IDs, including the ones missing from [`SUPPORTED_CHAINS`].
+    pub const ALL: [Self; 29] = [
+        Self::MAINNET,
+        Self::GOERLI,
+        Self::SEPOLIA,
+        Self::OPTIMISM,
+        Self::OPTIMISM_GOERLI,
+        Self::OPTIMISM_SEPOLIA,
+        Self::ARBITRUM_ONE,
+        Self::ARBITRUM_GOERLI,
+        Self::ARBITRUM_SEPOLIA,
+        Self::POLYGON,
+        Self::POLYGON_MUMBAI,
+        Self::CELO,
+        Self::CELO_ALFAJORES,
+        Self::GNOSIS,
+        Self::MOONBEAM,
+        Self::BNB,
+        Self::AVALANCHE,
+        Self::BASE_GOERLI,
+        Self::BASE_SEPOLIA,
+        Self::BASE,
+        Self::ZORA,
+        Self::ZORA_SEPOLIA,
+        Self::ROOTSTOCK,
+        Self::BLAST,
+        Self::ZKSYNC,
+        Self::WORLDCHAIN,
+        Self::UNICHAIN_SEPOLIA,
+        Self::UNICHAIN,
+        Self::MONAD_TESTNET,
+    ];
+
+    /// Returns the numeric chain ID.
+    #[inline]
+    #[must_use]
+    pub const fn id(self) -> u64 {
+        self as u64
+    }
+
+    /// Returns the chain with the numeric ID, if it is a [`ChainId`].
+    #[inline]
+    #[must_use]
+    pub const fn from_id(id: u64) -> Option<Self> {
+        let mut i = 0;
+        while i < Self::ALL.len() {
+            if Self::ALL[i] as u64 == id {
+                return Some(Self::ALL[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns the metadata of the chain.
+    #[inline]
+    #[must_use]
+    pub const fn info(self) -> ChainInfo {
+        use ChainType::*;
+        match self {
+            Self::MAINNET => ChainInfo::new("mainnet", "Ethereum", "ETH", false, L1, None, false),
+            Self::GOERLI => ChainInfo::new("goerli", "Goerli", "ETH", true, L1, None, true),
+            Self::SEPOLIA => ChainInfo::new("sepolia", "Sepolia", "ETH", true, L1, None, false),
+            Self::OPTIMISM => ChainInfo::new(
+                "optimism",
+                "OP Mainnet",
+                "ETH",
+                false,
+                OpStack,
+                Some(Self::MAINNET),
+                false,
+            ),
+            Self::OPTIMISM_GOERLI => ChainInfo::new(
+                "optimism-goerli",
+                "OP Goerli",
+                "ETH",
+                true,
+                OpStack,
+                Some(Self::GOERLI),
+                true,
+            ),
+            Self::OPTIMISM_SEPOLIA => ChainInfo::new(
+                "optimism-sepolia",
+                "OP Sepolia",
+                "ETH",
+                true,
+                OpStack,
+                Some(Self::SEPOLIA),
+                false,
+            ),
+            Self::ARBITRUM_ONE => ChainInfo::new(
+                "arbitrum-one",
+                "Arbitrum One",
+                "ETH",
+                false,
+                Arbitrum,
+                Some(Self::MAINNET),
+                false,
+            ),
+            Self::ARBITRUM_GOERLI => ChainInfo::new(
+                "arbitrum-goerli",
+                "Arbitrum Goerli",
+                "ETH",
+                true,
+                Arbitrum,
+                Some(Self::GOERLI),
+                true,
+            ),
+            Self::ARBITRUM_SEPOLIA => ChainInfo::new(
+                "arbitrum-sepolia",
+                "Arbitrum Sepolia",
+                "ETH",
+                true,
+                Arbitrum,
+                Some(Self::SEPOLIA),
+                false,
+            ),
+            Self::POLYGON => ChainInfo::new("polygon", "Polygon", "POL", false, L1, None, false),
+            Self::POLYGON_MUMBAI => ChainInfo::new(
+                "polygon-mumbai",
+                "Polygon Mumbai",
+                "MATIC",
+                true,
+                L1,
+                None,
+                true,
+            ),
+            Self::CELO => ChainInfo::new(
+                "celo",
+                "Celo",
+                "CELO",
+                false,
+                OpStack,
+                Some(Self::MAINNET),
+                false,
+            ),
+            // settles on Holesky, which is not a `ChainId`
+            Self::CELO_ALFAJORES => ChainInfo::new(
+                "celo-alfajores",
+                "Celo Alfajores",
+                "CELO",
+                true,
+                OpStack,
+                None,
+                false,
+            ),
+            Self::GNOSIS => ChainInfo::new("gnosis", "Gnosis", "xDAI", false, L1, None, false),
+            Self::MOONBEAM => {
+                ChainInfo::new("moonbeam", "Moonbeam", "GLMR", false, L1, None, false)
+            }
+            Self::BNB => ChainInfo::new("bnb", "BNB Smart Chain", "BNB", false, L1, None, false),
+            Self::AVALANCHE => {
+                ChainInfo::new("avalanche", "Avalanche", "AVAX", false, L1, None, false)
+            }
+            Self::BASE_GOERLI => ChainInfo::new(
+                "base-goerli",
+                "Base Goerli",
+                "ETH",
+                true,
+                OpStack,
+                Some(Self::GOERLI),
+                true,
+            ),
+            Self::BASE_SEPOLIA => ChainInfo::new(
+                "base-sepolia",
+                "Base Sepolia",
+                "ETH",
+                true,
+                OpStack,
+                Some(Self::SEPOLIA),
+                false,
+            ),
+            Self::BASE => ChainInfo::new(
+                "base",
+                "Base",
+                "ETH",
+                false,
+                OpStack,
+                Some(Self::MAINNET),
+                false,
+            ),
+            Self::ZORA => ChainInfo::new(
+                "zora",
+                "Zora",
+                "ETH",
+                false,
+                OpStack,
+                Some(Self::MAINNET),
+                false,
+            ),
+            Self::ZORA_SEPOLIA => ChainInfo::new(
+                "zora-sepolia",
+                "Zora Sepolia",
+                "ETH",
+                true,
+                OpStack,
+                Some(Self::SEPOLIA),
+                false,
+            ),
+            Self::ROOTSTOCK => {
+                ChainInfo::new("rootstock", "Rootstock", "RBTC", false, L1, None, false)
+            }
+            Self::BLAST => ChainInfo::new(
+                "blast",
+                "Blast",
+                "ETH",
+                false,
+                OpStack,
+                Some(Self::MAINNET),
+                false,
+            ),
+            Self::ZKSYNC => ChainInfo::new(
+                "zksync",
+                "ZKsync Era",
+                "ETH",
+                false,
+                ZkSync,
+                Some(Self::MAINNET),
+                false,
+            ),
+            Self::WORLDCHAIN => ChainInfo::new(
+                "worldchain",
+                "World Chain",
+                "ETH",
+                false,
+                OpStack,
+                Some(Self::MAINNET),
+                false,
+            ),
+            Self::UNICHAIN_SEPOLIA => ChainInfo::new(
+                "unichain-sepolia",
+                "Unichain Sepolia",
+                "ETH",
+                true,
+                OpStack,
+                Some(Self::SEPOLIA),
+                false,
+            ),
+            Self::UNICHAIN => ChainInfo::new(
+                "unichain",
+                "Unichain",
+                "ETH",
+                false,
+                OpStack,
+                Some(Self::MAINNET),
+                false,
+            ),
+            Self::MONAD_TESTNET => ChainInfo::new(
+                "monad-testnet",
+                "Monad Testnet",
+                "MON",
+                true,
+                L1,
+                None,
+                false,
+            ),
+        }
+    }
+
+    /// Returns the human-readable name of the chain, e.g. `"Arbitrum One"`.
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.info().name
+    }
+
+    /// Returns the symbol of the native currency of the chain.
+    #[inline]
+    #[must_use]
+    pub const fn native_symbol(self) -> &'static str {
+        self.info().native_symbol
+    }
+
+    /// Returns whether the chain is a testnet.
+    #[inline]
+    #[must_use]
+    pub const fn is_testnet(self) -> bool {
+        self.info().testnet
+    }
+
+    /// Returns the kind of network of the chain.
+    #[inline]
+    #[must_use]
+    pub const fn chain_type(self) -> ChainType {
+        self.info().chain_type
+    }
+
+    /// Returns the chain that a layer 2 settles on, if it is a [`ChainId`].
+    #[inline]
+    #[must_use]
+    pub const fn parent(self) -> Option<Self> {
+        self.info().parent
+    }
+
+    /// Returns whether the chain has been shut down or abandoned.
+    #[inline]
+    #[must_use]
+    pub const fn is_deprecated(self) -> bool {
+        self.info().deprecated
+    }
+}
+
+impl From<ChainId> for u64 {
+    #[inline]
+    fn from(chain_id: ChainId) -> Self {
+        chain_id.id()
+    }
+}
+
+impl TryFrom<u64> for ChainId {
+    type Error = Error;
+
+    /// Fails with [`Error::UnsupportedChainId`] if the ID is not a [`ChainId`].
+    #[inline]
+    fn try_from(id: u64) -> Result<Self, Self::Error> {
+        Self::from_id(id).ok_or(Error::UnsupportedChainId(id))
+    }
+}
+
+impl FromStr for ChainId {
+    type Err = Error;
+
+    /// Parses the slug of a chain such as `"base"` or `"arbitrum-one"`, ignoring case and
+    /// accepting underscores for hyphens, so that `"ARBITRUM_ONE"` parses too.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|chain_id| {
+                let slug = chain_id.info().slug;
+                slug.len() == s.len()
+                    && slug
+                        .bytes()
+                        .zip(s.bytes())
+                        .all(|(a, b)| a == b.to_ascii_lowercase() || a == b'-' && b == b'_')
+            })
+            .ok_or(Error::Parse {
+                what: "chain",
+                position: 0,
+            })
+    }
+}
+
+impl fmt::Display for ChainId {
+    /// Writes the slug of the chain, e.g. `arbitrum-one`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.info().slug)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(ChainId::try_from(8453), Ok(ChainId::BASE));
+        assert_eq!(
+            ChainId::try_from(12345),
+            Err(Error::UnsupportedChainId(12345))
+        );
+        for chain_id in ChainId::ALL {
+            assert_eq!(ChainId::try_from(u64::from(chain_id)), Ok(chain_id));
+        }
+    }
+
+    #[test]
+    fn test_from_str_and_display() {
+        assert_eq!("base".parse(), Ok(ChainId::BASE));
+        assert_eq!("arbitrum-one".parse(), Ok(ChainId::ARBITRUM_ONE));
+        assert_eq!("ARBITRUM_ONE".parse(), Ok(ChainId::ARBITRUM_ONE));
+        assert_eq!(
+            "arbitrum".parse::<ChainId>(),
+            Err(Error::Parse {
+                what: "chain",
+                position: 0
+            })
+        );
+        assert_eq!(ChainId::ARBITRUM_ONE.to_string(), "arbitrum-one");
+        for chain_id in ChainId::ALL {
+            assert_eq!(chain_id.to_string().parse(), Ok(chain_id));
+            assert_eq!(format!("{chain_id:?}").parse(), Ok(chain_id));
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        for (i, chain_id) in ChainId::ALL.into_iter().enumerate() {
+            assert!(!ChainId::ALL[..i].contains(&chain_id));
+        }
+        for chain_id in SUPPORTED_CHAINS {
+            assert!(ChainId::ALL.contains(&chain_id));
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        assert_eq!(ChainId::ARBITRUM_ONE.name(), "Arbitrum One");
+        assert_eq!(ChainId::POLYGON.native_symbol(), "POL");
+        assert!(ChainId::SEPOLIA.is_testnet());
+        assert!(!ChainId::BASE.is_testnet());
+        assert_eq!(ChainId::ZKSYNC.chain_type(), ChainType::ZkSync);
+        assert!(!ChainId::MAINNET.chain_type().is_l2());
+        assert_eq!(ChainId::BASE_SEPOLIA.parent(), Some(ChainId::SEPOLIA));
+        for chain_id in ChainId::ALL {
+            let info = chain_id.info();
+            assert_eq!(
+                info.deprecated,
+                chain_id == ChainId::POLYGON_MUMBAI
+                    || chain_id == ChainId::GOERLI
+                    || info.parent == Some(ChainId::GOERLI)
+            );
+            if let Some(parent) = info.parent {
+                assert!(info.chain_type.is_l2());
+                assert_eq!(parent.is_testnet(), chain_id.is_testnet());
+                assert!(!parent.chain_type().is_l2());
+            }
+        }
+    }
+}
@@ -28,3 +28,141 @@ pub enum Rounding {
 /// Represents the maximum amount contained in a uint256
 pub const MAX_UINT256: BigInt =
     BigInt::from_bits(BigUint::from_le_slice(&U256::MAX.to_le_bytes::<32>()).unwrap());
+
+/// The default fee tiers of Uniswap v3 pools, in hundredths of a basis point (pips).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FeeAmount {
+    /// 0.01%
+    LOWEST = 100,
+    /// 0.05%
+    LOW = 500,
+    /// 0.3%
+    MEDIUM = 3000,
+    /// 1%
+    HIGH = 10000,
+}
+
+impl FeeAmount {
+    /// All default fee tiers, from lowest to highest.
+    pub const ALL: [Self; 4] = [Self::LOWEST, Self::LOW, Self::MEDIUM, Self::HIGH];
+
+    /// Returns the fee in pips, i.e. hundredths of a basis point.
+    #[inline]
+    #[must_use]
+    pub const fn pips(self) -> u32 {
+        self as u32
+    }
+
+    /// Returns the tick spacing of pools with the fee tier.
+    #[inline]
+    #[must_use]
+    pub const fn tick_spacing(self) -> i32 {
+        match self {
+            Self::LOWEST => 1,
+            Self::LOW => 10,
+            Self::MEDIUM => 60,
+            Self::HIGH => 200,
+        }
+    }
+}
+
+impl TryFrom<u32> for FeeAmount {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(pips: u32) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|fee| fee.pips() == pips)
+            .ok_or(Error::UnknownFeeAmount(pips))
+    }
+}
+
+/// The flag marking a Uniswap v4 pool fee as dynamic, i.e. set by the hook.
+pub const DYNAMIC_FEE_FLAG: u32 = 0x80_0000;
+
+/// The maximum static LP fee of a Uniswap v4 pool, 100% in pips.
+pub const MAX_LP_FEE: u32 = 1_000_000;
+
+/// The LP fee of a Uniswap v4 pool, as encoded in its pool key.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum LpFee {
+    /// A fixed fee in pips
+    Static(u32),
+    /// A fee set by the hook of the pool
+    Dynamic,
+}
+
+impl LpFee {
+    /// Decodes the fee field of a pool key.
+    #[inline]
+    pub const fn from_raw(fee: u32) -> Result<Self, Error> {
+        if fee == DYNAMIC_FEE_FLAG {
+            Ok(Self::Dynamic)
+        } else if fee <= MAX_LP_FEE {
+            Ok(Self::Static(fee))
+        } else {
+            Err(Error::out_of_range("LP fee", fee as u64, MAX_LP_FEE as u64))
+        }
+    }
+
+    /// Encodes the fee into the fee field of a pool key.
+    #[inline]
+    #[must_use]
+    pub const fn to_raw(self) -> u32 {
+        match self {
+            Self::Static(fee) => fee,
+            Self::Dynamic => DYNAMIC_FEE_FLAG,
+        }
+    }
+
+    /// Returns whether the fee is set by the hook of the pool.
+    #[inline]
+    #[must_use]
+    pub const fn is_dynamic(self) -> bool {
+        matches!(self, Self::Dynamic)
+    }
+
+    /// Returns the fee as a [`Percent`], unless it is dynamic.
+    #[inline]
+    #[must_use]
+    pub fn percent(self) -> Option<Percent> {
+        match self {
+            Self::Static(fee) => Some(Percent::from_pips(fee)),
+            Self::Dynamic => None,
+        }
+    }
+}
+
+impl From<FeeAmount> for LpFee {
+    #[inline]
+    fn from(fee: FeeAmount) -> Self {
+        Self::Static(fee.pips())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_amount() {
+        assert_eq!(FeeAmount::MEDIUM.pips(), 3000);
+        assert_eq!(FeeAmount::HIGH.tick_spacing(), 200);
+        assert_eq!(FeeAmount::try_from(500), Ok(FeeAmount::LOW));
+        assert_eq!(FeeAmount::try_from(501), Err(Error::UnknownFeeAmount(501)));
+    }
+
+    #[test]
+    fn test_lp_fee() {
+        assert_eq!(LpFee::from_raw(0x80_0000), Ok(LpFee::Dynamic));
+        assert_eq!(LpFee::from_raw(3000), Ok(FeeAmount::MEDIUM.into()));
+        assert_eq!(
+            LpFee::from_raw(1_000_001),
+            Err(Error::out_of_range("LP fee", 1_000_001, 1_000_000))
+        );
+        assert_eq!(LpFee::Dynamic.to_raw(), DYNAMIC_FEE_FLAG);
+        assert_eq!(LpFee::Dynamic.percent(), None);
+        assert_eq!(LpFee::Static(3000).percent(), Some(Percent::new(3, 1000)));
+    }
+}
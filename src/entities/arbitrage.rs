@@ -0,0 +1,233 @@
+use crate::prelude::*;
+use alloc::{collections::BTreeMap, vec};
+use num_traits::Float;
+
+/// Relaxations smaller than this are treated as floating point noise.
+const TOLERANCE: f64 = 1e-12;
+
+/// A cycle of prices whose product exceeds one, found in a [`PriceGraph`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArbitrageCycle {
+    /// The currencies traversed, starting and ending with the same currency
+    pub path: Vec<Currency>,
+    /// The exact product of the prices around the cycle
+    pub rate: Fraction,
+    /// The profit implied by the rate, i.e. `rate - 1`
+    pub profit: Percent,
+}
+
+impl ArbitrageCycle {
+    /// Returns the distinct currencies involved in the cycle.
+    #[inline]
+    #[must_use]
+    pub fn currencies(&self) -> &[Currency] {
+        &self.path[..self.path.len() - 1]
+    }
+}
+
+impl PriceGraph {
+    /// Detects cycles of prices whose product exceeds one.
+    ///
+    /// Cycles are found with Bellman-Ford over the negative logarithms of the prices, then the
+    /// product of each candidate is verified with exact rational arithmetic, so that floating point
+    /// noise never yields a false positive. Not every profitable cycle is necessarily reported, but
+    /// at least one is found whenever any exists with a profit above floating point precision.
+    ///
+    /// returns: the distinct cycles found, sorted by decreasing profit
+    #[inline]
+    #[must_use]
+    pub fn find_arbitrage(&self) -> Vec<ArbitrageCycle> {
+        let ids: Vec<CurrencyId> = self.currencies().map(BaseCurrency::currency_id).collect();
+        let edges: Vec<(usize, usize, f64, &Price<Currency, Currency>)> = self
+            .prices()
+            .filter_map(|price| {
+                let from = ids.binary_search(&price.base_currency.currency_id()).ok()?;
+                let to = ids
+                    .binary_search(&price.quote_currency.currency_id())
+                    .ok()?;
+                let weight = -Float::ln(price.to_decimal().to_f64());
+                weight.is_finite().then_some((from, to, weight, price))
+            })
+            .collect();
+
+        // start from a virtual source connected to every currency
+        let mut distances = vec![0.0_f64; ids.len()];
+        let mut predecessors: Vec<Option<usize>> = vec![None; ids.len()];
+        let mut relaxed = Vec::new();
+        for round in 0..=ids.len() {
+            relaxed.clear();
+            for (index, &(from, to, weight, _)) in edges.iter().enumerate() {
+                if distances[from] + weight < distances[to] - TOLERANCE {
+                    distances[to] = distances[from] + weight;
+                    predecessors[to] = Some(index);
+                    relaxed.push(to);
+                }
+            }
+            if relaxed.is_empty() || round == ids.len() {
+                break;
+            }
+        }
+
+        let mut cycles: BTreeMap<Vec<usize>, ArbitrageCycle> = BTreeMap::new();
+        for &start in &relaxed {
+            let Some(cycle) = extract_cycle(start, ids.len(), &predecessors, |edge| edges[edge].0)
+            else {
+                continue;
+            };
+            if cycles.contains_key(&cycle) {
+                continue;
+            }
+            let prices: Vec<_> = cycle.iter().map(|&edge| edges[edge].3).collect();
+            if let Some(arbitrage) = verify(&prices) {
+                cycles.insert(cycle, arbitrage);
+            }
+        }
+
+        let mut cycles: Vec<ArbitrageCycle> = cycles.into_values().collect();
+        cycles.sort_by(|a, b| b.rate.cmp(&a.rate));
+        cycles
+    }
+}
+
+/// Follows the predecessor edges back from `start` onto a cycle, returning the edges of the cycle
+/// in order, rotated to start at the edge leaving the lowest currency.
+#[inline]
+fn extract_cycle(
+    start: usize,
+    currencies: usize,
+    predecessors: &[Option<usize>],
+    source: impl Fn(usize) -> usize,
+) -> Option<Vec<usize>> {
+    // walking back once per currency is guaranteed to end up on a cycle
+    let mut node = start;
+    for _ in 0..currencies {
+        node = source(predecessors[node]?);
+    }
+    let mut cycle = vec![predecessors[node]?];
+    let mut current = source(cycle[0]);
+    while current != node {
+        let edge = predecessors[current]?;
+        cycle.push(edge);
+        current = source(edge);
+    }
+    cycle.reverse();
+    let first = (0..cycle.len()).min_by_key(|&i| source(cycle[i]))?;
+    cycle.rotate_left(first);
+    Some(cycle)
+}
+
+/// Computes the exact product of the prices around a cycle, returning the cycle if it exceeds one.
+#[inline]
+fn verify(prices: &[&Price<Currency, Currency>]) -> Option<ArbitrageCycle> {
+    let rate = prices.iter().fold(Fraction::new(1, 1), |rate, price| {
+        rate * price.as_fraction()
+    });
+    if rate <= Fraction::new(1, 1) {
+        return None;
+    }
+    let mut path: Vec<Currency> = prices
+        .iter()
+        .map(|price| price.base_currency.clone())
+        .collect();
+    path.push(prices[0].base_currency.clone());
+    let profit = rate.clone() - Fraction::new(1, 1);
+    Some(ArbitrageCycle {
+        path,
+        profit: Percent::new(profit.numerator, profit.denominator),
+        rate,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref WETH: Currency = WETH9::on_chain(1).unwrap().into();
+        static ref USDC: Currency =
+            token!(1, "A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", 6, "USDC").into();
+        static ref DAI: Currency =
+            token!(1, "6B175474E89094C44Da98b954EedeAC495271d0F", 18, "DAI").into();
+        static ref USDT: Currency =
+            token!(1, "dAC17F958D2ee523a2206206994597C13D831ec7", 6, "USDT").into();
+    }
+
+    /// Creates a price from human readable amounts of the base and quote currencies.
+    fn price(
+        base: &Currency,
+        quote: &Currency,
+        base_amount: i64,
+        quote_amount: i64,
+    ) -> Price<Currency, Currency> {
+        Price::new(
+            base.clone(),
+            quote.clone(),
+            BigInt::from(base_amount) * BigInt::from(10).pow(base.decimals() as u32),
+            BigInt::from(quote_amount) * BigInt::from(10).pow(quote.decimals() as u32),
+        )
+    }
+
+    #[test]
+    fn test_no_arbitrage() {
+        let mut graph = PriceGraph::new();
+        graph.add_price(price(&WETH, &USDC, 1, 2000)).unwrap();
+        graph.add_price(price(&USDC, &DAI, 1, 1)).unwrap();
+        graph.add_price(price(&WETH, &DAI, 1, 2000)).unwrap();
+        assert!(graph.find_arbitrage().is_empty());
+    }
+
+    #[test]
+    fn test_triangular_arbitrage() {
+        let mut graph = PriceGraph::new();
+        graph.add_price(price(&WETH, &USDC, 1, 2000)).unwrap();
+        graph.add_price(price(&USDC, &DAI, 1, 1)).unwrap();
+        graph.add_price(price(&WETH, &DAI, 1, 2100)).unwrap();
+
+        let cycles = graph.find_arbitrage();
+        assert_eq!(cycles.len(), 1);
+        let cycle = &cycles[0];
+        // WETH -> DAI -> USDC -> WETH yields 2100 / 2000
+        assert_eq!(cycle.currencies().len(), 3);
+        assert_eq!(cycle.path.first(), cycle.path.last());
+        assert_eq!(cycle.rate, Fraction::new(21, 20));
+        assert_eq!(cycle.profit.to_fixed(2, None), "5.00");
+    }
+
+    #[test]
+    fn test_explicit_spread_is_not_arbitrage() {
+        let mut graph = PriceGraph::new();
+        graph.add_price(price(&USDC, &USDT, 100, 99)).unwrap();
+        graph.add_price(price(&USDT, &USDC, 100, 99)).unwrap();
+        assert!(graph.find_arbitrage().is_empty());
+
+        // a crossed book is profitable
+        graph.add_price(price(&USDT, &USDC, 100, 102)).unwrap();
+        let cycles = graph.find_arbitrage();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].rate, Fraction::new(99 * 102, 100 * 100));
+    }
+
+    #[test]
+    fn test_verify_rejects_float_noise() {
+        // 10 * 58/10 * 1/58 is exactly one, but the rounded rates multiply to slightly more
+        let prices = [
+            Price::new(USDC.clone(), USDT.clone(), 1, 10),
+            Price::new(USDT.clone(), DAI.clone(), 10, 58),
+            Price::new(DAI.clone(), USDC.clone(), 58, 1),
+        ];
+        let log_sum: f64 = prices
+            .iter()
+            .map(|price| -Float::ln(price.to_decimal().to_f64()))
+            .sum();
+        assert!(log_sum < 0.0);
+        assert!(verify(&prices.iter().collect::<Vec<_>>()).is_none());
+
+        let mut graph = PriceGraph::new();
+        for price in prices {
+            graph.add_price(price).unwrap();
+        }
+        assert!(graph.find_arbitrage().is_empty());
+    }
+}
@@ -0,0 +1,352 @@
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+
+/// A source of prices between currencies, used to value [`Balances`].
+pub trait PriceSource {
+    /// Returns the price of `base` in terms of `quote`, if known.
+    fn price(&self, base: &Currency, quote: &Currency) -> Option<Price<Currency, Currency>>;
+}
+
+impl PriceSource for PriceGraph {
+    #[inline]
+    fn price(&self, base: &Currency, quote: &Currency) -> Option<Price<Currency, Currency>> {
+        self.cross_rate(base, quote).map(|path| path.price)
+    }
+}
+
+impl<F> PriceSource for F
+where
+    F: Fn(&Currency, &Currency) -> Option<Price<Currency, Currency>>,
+{
+    #[inline]
+    fn price(&self, base: &Currency, quote: &Currency) -> Option<Price<Currency, Currency>> {
+        self(base, quote)
+    }
+}
+
+/// The value of [`Balances`] in a single quote currency.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Valuation {
+    /// The total value of the balances that could be priced
+    pub total: CurrencyAmount<Currency>,
+    /// The currencies that could not be priced and are excluded from the total
+    pub missing: Vec<Currency>,
+}
+
+/// A balance sheet of amounts in many currencies, keyed by [`CurrencyId`].
+///
+/// Amounts are signed, so the same type describes both holdings and the deltas between them.
+/// Currencies whose balance becomes zero are removed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Balances {
+    amounts: BTreeMap<CurrencyId, CurrencyAmount<Currency>>,
+}
+
+impl Balances {
+    /// Creates empty [`Balances`].
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            amounts: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the balance of the currency, if it is not zero.
+    #[inline]
+    pub fn get(&self, currency: &impl BaseCurrency) -> Option<&CurrencyAmount<Currency>> {
+        self.amounts.get(&currency.currency_id())
+    }
+
+    /// Returns the number of currencies with a non-zero balance.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.amounts.len()
+    }
+
+    /// Returns whether all balances are zero.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.amounts.is_empty()
+    }
+
+    /// Returns the non-zero balances, ordered by [`CurrencyId`].
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &CurrencyAmount<Currency>> {
+        self.amounts.values()
+    }
+
+    /// Adds a non-negative amount to the balance of its currency.
+    #[inline]
+    pub fn credit<T: BaseCurrency + Into<Currency>>(
+        &mut self,
+        amount: &CurrencyAmount<T>,
+    ) -> Result<(), Error> {
+        if amount.numerator < BigInt::ZERO {
+            return Err(Error::Negative);
+        }
+        self.apply(amount)
+    }
+
+    /// Subtracts a non-negative amount from the balance of its currency, failing if the balance
+    /// is insufficient.
+    #[inline]
+    pub fn debit<T: BaseCurrency + Into<Currency>>(
+        &mut self,
+        amount: &CurrencyAmount<T>,
+    ) -> Result<(), Error> {
+        if amount.numerator < BigInt::ZERO {
+            return Err(Error::Negative);
+        }
+        let balance = self.get(&amount.currency);
+        if balance.map_or(amount.numerator > BigInt::ZERO, |balance| {
+            balance.as_fraction() < amount.as_fraction()
+        }) {
+            return Err(Error::InsufficientBalance {
+                available: balance
+                    .map_or(U256::ZERO, |balance| U256::from_big_int(balance.quotient())),
+                required: U256::from_big_int(amount.quotient()),
+            });
+        }
+        self.apply(&CurrencyAmount::from_fractional_amount(
+            amount.currency.clone(),
+            -amount.numerator,
+            amount.denominator,
+        )?)
+    }
+
+    /// Adds a signed delta to the balance of its currency, which may become negative.
+    ///
+    /// Fails with [`Error::CurrencyMismatch`] if the currency is already in the balances with
+    /// different decimals.
+    #[inline]
+    pub fn apply<T: BaseCurrency + Into<Currency>>(
+        &mut self,
+        delta: &CurrencyAmount<T>,
+    ) -> Result<(), Error> {
+        let id = delta.currency.currency_id();
+        let sum = match self.amounts.get(&id) {
+            Some(balance) => {
+                if balance.currency.decimals() != delta.currency.decimals() {
+                    return Err(Error::CurrencyMismatch);
+                }
+                let sum = balance.as_fraction() + delta.as_fraction();
+                CurrencyAmount::from_fractional_amount(
+                    balance.currency.clone(),
+                    sum.numerator,
+                    sum.denominator,
+                )?
+            }
+            None => CurrencyAmount::from_fractional_amount(
+                delta.currency.clone().into(),
+                delta.numerator,
+                delta.denominator,
+            )?,
+        };
+        if sum.numerator == BigInt::ZERO {
+            self.amounts.remove(&id);
+        } else {
+            self.amounts.insert(id, sum);
+        }
+        Ok(())
+    }
+
+    /// Adds all balances of `other`.
+    ///
+    /// On error the balances may be partially merged.
+    #[inline]
+    pub fn merge(&mut self, other: &Self) -> Result<(), Error> {
+        other.iter().try_for_each(|amount| self.apply(amount))
+    }
+
+    /// Returns the signed deltas that turn `before` into these balances.
+    #[inline]
+    pub fn delta(&self, before: &Self) -> Result<Self, Error> {
+        let mut delta = self.clone();
+        for amount in before.iter() {
+            delta.apply(&CurrencyAmount::from_fractional_amount(
+                amount.currency.clone(),
+                -amount.numerator,
+                amount.denominator,
+            )?)?;
+        }
+        Ok(delta)
+    }
+
+    /// Values all balances in the quote currency.
+    ///
+    /// Balances without a price are excluded from the total and reported as missing instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `quote`: the currency to value the balances in
+    /// * `prices`: the source of the price of each currency in terms of `quote`
+    #[inline]
+    pub fn value_in(
+        &self,
+        quote: &Currency,
+        prices: &impl PriceSource,
+    ) -> Result<Valuation, Error> {
+        let mut total = Fraction::new(0, 1);
+        let mut missing = Vec::new();
+        for amount in self.iter() {
+            if amount.currency.equals(quote) {
+                total = total + amount.as_fraction();
+                continue;
+            }
+            match prices.price(&amount.currency, quote) {
+                Some(price) => {
+                    if !price.quote_currency.equals(quote) {
+                        return Err(Error::CurrencyMismatch);
+                    }
+                    total = total + price.quote(amount)?.as_fraction();
+                }
+                None => missing.push(amount.currency.clone()),
+            }
+        }
+        Ok(Valuation {
+            total: CurrencyAmount::from_fractional_amount(
+                quote.clone(),
+                total.numerator,
+                total.denominator,
+            )?,
+            missing,
+        })
+    }
+}
+
+impl<'a> IntoIterator for &'a Balances {
+    type Item = &'a CurrencyAmount<Currency>;
+    type IntoIter = alloc::collections::btree_map::Values<'a, CurrencyId, CurrencyAmount<Currency>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.amounts.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token;
+    use alloc::vec;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref USDC: Token = token!(1, "A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", 6, "USDC");
+        static ref WETH: Token = WETH9::on_chain(1).unwrap();
+        static ref PEPE: Token = token!(1, "6982508145454Ce325dDbE47a25d4ec3d2311933", 18, "PEPE");
+    }
+
+    fn amount<T: BaseCurrency>(currency: &T, raw: i64) -> CurrencyAmount<T> {
+        CurrencyAmount::from_raw_amount(currency.clone(), raw).unwrap()
+    }
+
+    #[test]
+    fn test_credit_and_debit() {
+        let mut balances = Balances::new();
+        balances.credit(&amount(&USDC.clone(), 100)).unwrap();
+        balances.credit(&amount(&USDC.clone(), 50)).unwrap();
+        balances.credit(&amount(&Ether::on_chain(1), 7)).unwrap();
+        assert_eq!(balances.len(), 2);
+        assert_eq!(
+            balances.get(&USDC.clone()).unwrap().quotient(),
+            BigInt::from(150)
+        );
+
+        balances.debit(&amount(&USDC.clone(), 150)).unwrap();
+        assert_eq!(balances.get(&USDC.clone()), None);
+        assert_eq!(
+            balances.debit(&amount(&Ether::on_chain(1), 8)),
+            Err(Error::InsufficientBalance {
+                available: U256::from(7),
+                required: U256::from(8),
+            })
+        );
+        assert_eq!(
+            balances.debit(&amount(&WETH.clone(), 1)),
+            Err(Error::InsufficientBalance {
+                available: U256::ZERO,
+                required: U256::from(1),
+            })
+        );
+        assert_eq!(
+            balances.credit(&amount(&WETH.clone(), -1)),
+            Err(Error::Negative)
+        );
+        // the native currency and its wrapped token are different currencies
+        assert!(balances.get(&WETH.clone()).is_none());
+    }
+
+    #[test]
+    fn test_decimals_mismatch() {
+        let mut balances = Balances::new();
+        balances.credit(&amount(&USDC.clone(), 100)).unwrap();
+        let usdc_18 = token!(1, "A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", 18, "USDC");
+        assert_eq!(
+            balances.credit(&amount(&usdc_18, 1)),
+            Err(Error::CurrencyMismatch)
+        );
+    }
+
+    #[test]
+    fn test_merge_and_delta() {
+        let mut before = Balances::new();
+        before.credit(&amount(&USDC.clone(), 100)).unwrap();
+        before.credit(&amount(&WETH.clone(), 10)).unwrap();
+
+        let mut after = before.clone();
+        after.debit(&amount(&USDC.clone(), 30)).unwrap();
+        after.credit(&amount(&PEPE.clone(), 1000)).unwrap();
+
+        let delta = after.delta(&before).unwrap();
+        assert_eq!(delta.len(), 2);
+        assert_eq!(
+            delta.get(&USDC.clone()).unwrap().quotient(),
+            BigInt::from(-30)
+        );
+        assert_eq!(delta.get(&WETH.clone()), None);
+
+        let mut merged = before.clone();
+        merged.merge(&delta).unwrap();
+        assert_eq!(merged, after);
+        assert_eq!((&merged).into_iter().count(), 3);
+    }
+
+    #[test]
+    fn test_value_in() {
+        let mut balances = Balances::new();
+        balances.credit(&amount(&USDC.clone(), 1_000_000)).unwrap();
+        balances
+            .credit(&amount(&WETH.clone(), 500_000_000_000_000_000))
+            .unwrap();
+        balances.credit(&amount(&PEPE.clone(), 1)).unwrap();
+
+        let mut graph = PriceGraph::new();
+        // 1 WETH = 2000 USDC
+        graph
+            .add_price(Price::new(
+                WETH.clone().into(),
+                USDC.clone().into(),
+                1_000_000_000_000_000_000_i64,
+                2_000_000_000_i64,
+            ))
+            .unwrap();
+
+        let usdc: Currency = USDC.clone().into();
+        let valuation = balances.value_in(&usdc, &graph).unwrap();
+        assert_eq!(valuation.total.to_exact(), "1001");
+        assert_eq!(valuation.missing, vec![Currency::from(PEPE.clone())]);
+
+        let weth: Currency = WETH.clone().into();
+        let valuation = balances
+            .value_in(&weth, &|base: &Currency, quote: &Currency| {
+                graph.price(base, quote).cloned()
+            })
+            .unwrap();
+        assert_eq!(valuation.total.to_exact(), "0.5005");
+        assert_eq!(valuation.missing.len(), 1);
+    }
+}
@@ -15,6 +15,13 @@ pub trait BaseCurrency: BaseCurrencyCore + Clone {
 
     /// Returns a Token that represents the wrapped equivalent of the native currency
     fn wrapped(&self) -> &Token;
+
+    /// Returns the identity of the currency, which is equal for currencies that are
+    /// [`BaseCurrency::equals`] and can be used as a map key
+    #[inline]
+    fn currency_id(&self) -> CurrencyId {
+        CurrencyId::of(self)
+    }
 }
 
 pub trait BaseCurrencyCore {
@@ -0,0 +1,339 @@
+use crate::prelude::*;
+use alloy_primitives::ChainId;
+use fastnum::i512;
+
+/// How a member of a [`CanonicalAsset`] relates to the underlying asset.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum AssetVariant {
+    /// The issuer's own deployment on the chain, e.g. native USDC on Arbitrum.
+    Canonical,
+
+    /// A bridged representation of the asset, e.g. USDC.e on Arbitrum.
+    Bridged,
+}
+
+/// A currency belonging to a [`CanonicalAsset`].
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct AssetMember {
+    /// The currency on its chain
+    pub currency: Currency,
+    /// How the currency relates to the underlying asset
+    pub variant: AssetVariant,
+}
+
+/// A group of currencies on different chains that represent the same underlying asset.
+///
+/// [`BaseCurrency::equals`] deliberately fails across chains, so this is an explicit, opt-in
+/// equivalence layer on top of it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CanonicalAsset {
+    /// The symbol of the underlying asset
+    pub symbol: String,
+    members: Vec<AssetMember>,
+}
+
+impl CanonicalAsset {
+    /// Creates a new [`CanonicalAsset`] without any members.
+    #[inline]
+    #[must_use]
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            members: Vec::new(),
+        }
+    }
+
+    /// Adds the issuer's own deployment of the asset on a chain.
+    #[inline]
+    #[must_use]
+    pub fn with_canonical(self, currency: impl Into<Currency>) -> Self {
+        self.with_member(currency, AssetVariant::Canonical)
+    }
+
+    /// Adds a bridged representation of the asset on a chain.
+    #[inline]
+    #[must_use]
+    pub fn with_bridged(self, currency: impl Into<Currency>) -> Self {
+        self.with_member(currency, AssetVariant::Bridged)
+    }
+
+    /// Adds a currency of the given variant, replacing an equal existing member.
+    #[inline]
+    #[must_use]
+    pub fn with_member(mut self, currency: impl Into<Currency>, variant: AssetVariant) -> Self {
+        let currency = currency.into();
+        self.members
+            .retain(|member| !member.currency.equals(&currency));
+        self.members.push(AssetMember { currency, variant });
+        self
+    }
+
+    /// Returns all members of the asset.
+    #[inline]
+    #[must_use]
+    pub fn members(&self) -> &[AssetMember] {
+        &self.members
+    }
+
+    /// Returns whether the currency is a member of the asset.
+    #[inline]
+    pub fn contains(&self, currency: &impl BaseCurrency) -> bool {
+        self.member(currency).is_some()
+    }
+
+    /// Returns the membership of the currency, if any.
+    #[inline]
+    pub fn member(&self, currency: &impl BaseCurrency) -> Option<&AssetMember> {
+        self.members
+            .iter()
+            .find(|member| member.currency.equals(currency))
+    }
+
+    /// Returns the members of the asset on a chain.
+    #[inline]
+    pub fn on_chain(&self, chain_id: ChainId) -> impl Iterator<Item = &AssetMember> {
+        self.members
+            .iter()
+            .filter(move |member| member.currency.chain_id() == chain_id)
+    }
+
+    /// Returns the canonical member of the asset on a chain, if any.
+    #[inline]
+    #[must_use]
+    pub fn canonical_on_chain(&self, chain_id: ChainId) -> Option<&Currency> {
+        self.on_chain(chain_id)
+            .find(|member| member.variant == AssetVariant::Canonical)
+            .map(|member| &member.currency)
+    }
+
+    /// Converts an amount of one member into the equivalent amount of another member, rescaling
+    /// for the difference in decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount`: the amount of a member of the asset
+    /// * `target`: the member to convert to
+    #[inline]
+    pub fn rescale<T: BaseCurrency, U: BaseCurrency>(
+        &self,
+        amount: &CurrencyAmount<T>,
+        target: U,
+    ) -> Result<CurrencyAmount<U>, Error> {
+        if !self.contains(&amount.currency) || !self.contains(&target) {
+            return Err(Error::CurrencyMismatch);
+        }
+        let scaled = amount.as_fraction()
+            * Fraction::new(
+                i512!(10).pow(target.decimals() as u32),
+                i512!(10).pow(amount.currency.decimals() as u32),
+            );
+        CurrencyAmount::from_fractional_amount(target, scaled.numerator, scaled.denominator)
+    }
+}
+
+/// A collection of [`CanonicalAsset`]s indexed by their members.
+#[derive(Clone, Debug, Default)]
+pub struct AssetRegistry {
+    assets: Vec<CanonicalAsset>,
+    index: HashMap<CurrencyId, usize>,
+}
+
+impl AssetRegistry {
+    /// Creates an empty [`AssetRegistry`].
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an asset.
+    ///
+    /// A currency can only belong to one asset, so this fails if any member is already registered.
+    #[inline]
+    pub fn register(&mut self, asset: CanonicalAsset) -> Result<(), Error> {
+        if asset
+            .members
+            .iter()
+            .any(|member| self.index.contains_key(&member.currency.currency_id()))
+        {
+            return Err(Error::DuplicateCurrency);
+        }
+        let position = self.assets.len();
+        self.index.extend(
+            asset
+                .members
+                .iter()
+                .map(|member| (member.currency.currency_id(), position)),
+        );
+        self.assets.push(asset);
+        Ok(())
+    }
+
+    /// Returns all registered assets.
+    #[inline]
+    #[must_use]
+    pub fn assets(&self) -> &[CanonicalAsset] {
+        &self.assets
+    }
+
+    /// Returns the asset the currency belongs to, if any.
+    #[inline]
+    pub fn asset_of(&self, currency: &impl BaseCurrency) -> Option<&CanonicalAsset> {
+        self.index
+            .get(&currency.currency_id())
+            .map(|&position| &self.assets[position])
+    }
+
+    /// Returns the members of the currency's asset on another chain.
+    #[inline]
+    pub fn equivalents_on(
+        &self,
+        currency: &impl BaseCurrency,
+        chain_id: ChainId,
+    ) -> impl Iterator<Item = &AssetMember> {
+        self.asset_of(currency)
+            .into_iter()
+            .flat_map(move |asset| asset.on_chain(chain_id))
+    }
+
+    /// Returns whether two currencies represent the same underlying asset.
+    #[inline]
+    pub fn are_equivalent(&self, a: &impl BaseCurrency, b: &impl BaseCurrency) -> bool {
+        a.equals(b)
+            || matches!(
+                (self.index.get(&a.currency_id()), self.index.get(&b.currency_id())),
+                (Some(x), Some(y)) if x == y
+            )
+    }
+
+    /// Converts an amount into the equivalent amount of another member of the same asset.
+    #[inline]
+    pub fn rescale<T: BaseCurrency, U: BaseCurrency>(
+        &self,
+        amount: &CurrencyAmount<T>,
+        target: U,
+    ) -> Result<CurrencyAmount<U>, Error> {
+        self.asset_of(&amount.currency)
+            .ok_or(Error::CurrencyMismatch)?
+            .rescale(amount, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref USDC_MAINNET: Token =
+            token!(1, "A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", 6, "USDC");
+        static ref USDC_BASE: Token =
+            token!(8453, "833589fCD6eDb6E08f4c7C32D4f71b54bdA02913", 6, "USDC");
+        static ref USDC_ARBITRUM: Token =
+            token!(42161, "af88d065e77c8cC2239327C5EDb3A432268e5831", 6, "USDC");
+        static ref USDCE_ARBITRUM: Token = token!(
+            42161,
+            "FF970A61A04b1cA14834A43f5dE4533eBDDB5CC8",
+            6,
+            "USDC.e"
+        );
+        static ref USDC_BNB: Token =
+            token!(56, "8AC76a51cc950d9822D68b83fE1Ad97B32Cd580d", 18, "USDC");
+        static ref DAI_MAINNET: Token =
+            token!(1, "6B175474E89094C44Da98b954EedeAC495271d0F", 18, "DAI");
+        static ref USDC: CanonicalAsset = CanonicalAsset::new("USDC")
+            .with_canonical(USDC_MAINNET.clone())
+            .with_canonical(USDC_BASE.clone())
+            .with_canonical(USDC_ARBITRUM.clone())
+            .with_bridged(USDCE_ARBITRUM.clone())
+            .with_canonical(USDC_BNB.clone());
+    }
+
+    #[test]
+    fn test_on_chain() {
+        assert_eq!(USDC.on_chain(42161).count(), 2);
+        assert_eq!(
+            USDC.canonical_on_chain(42161),
+            Some(&Currency::Token(USDC_ARBITRUM.clone()))
+        );
+        assert_eq!(USDC.canonical_on_chain(10), None);
+        assert_eq!(
+            USDC.member(&USDCE_ARBITRUM.clone()).unwrap().variant,
+            AssetVariant::Bridged
+        );
+        assert!(!USDC.contains(&DAI_MAINNET.clone()));
+    }
+
+    #[test]
+    fn test_with_member_replaces_equal() {
+        let asset = CanonicalAsset::new("USDC")
+            .with_bridged(USDC_MAINNET.clone())
+            .with_canonical(USDC_MAINNET.clone());
+        assert_eq!(asset.members().len(), 1);
+        assert_eq!(asset.members()[0].variant, AssetVariant::Canonical);
+    }
+
+    #[test]
+    fn test_rescale() {
+        let amount = CurrencyAmount::from_raw_amount(USDC_MAINNET.clone(), 1_500_000).unwrap();
+
+        let on_base = USDC.rescale(&amount, USDC_BASE.clone()).unwrap();
+        assert_eq!(on_base.quotient(), BigInt::from(1_500_000));
+
+        let on_bnb = USDC.rescale(&amount, USDC_BNB.clone()).unwrap();
+        assert_eq!(
+            on_bnb.quotient(),
+            BigInt::from(1_500_000_000_000_000_000_i64)
+        );
+        assert_eq!(on_bnb.to_exact(), amount.to_exact());
+
+        let back = USDC.rescale(&on_bnb, USDC_MAINNET.clone()).unwrap();
+        assert_eq!(back, amount);
+
+        assert_eq!(
+            USDC.rescale(&amount, DAI_MAINNET.clone()),
+            Err(Error::CurrencyMismatch)
+        );
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = AssetRegistry::new();
+        registry.register(USDC.clone()).unwrap();
+        registry
+            .register(CanonicalAsset::new("DAI").with_canonical(DAI_MAINNET.clone()))
+            .unwrap();
+
+        assert_eq!(
+            registry.asset_of(&USDC_BASE.clone()).unwrap().symbol,
+            "USDC"
+        );
+        assert!(registry.are_equivalent(&USDC_BASE.clone(), &USDCE_ARBITRUM.clone()));
+        assert!(!registry.are_equivalent(&USDC_BASE.clone(), &DAI_MAINNET.clone()));
+        assert_eq!(
+            registry
+                .equivalents_on(&USDC_MAINNET.clone(), 42161)
+                .count(),
+            2
+        );
+        assert_eq!(
+            registry.equivalents_on(&DAI_MAINNET.clone(), 8453).count(),
+            0
+        );
+
+        let amount = CurrencyAmount::from_raw_amount(USDC_BNB.clone(), 10_i64.pow(18)).unwrap();
+        assert_eq!(
+            registry
+                .rescale(&amount, USDC_BASE.clone())
+                .unwrap()
+                .quotient(),
+            BigInt::from(1_000_000)
+        );
+
+        assert_eq!(
+            registry.register(CanonicalAsset::new("USDC").with_canonical(USDC_BASE.clone())),
+            Err(Error::DuplicateCurrency)
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use alloy_primitives::ChainId;
+use alloy_primitives::{address, ChainId};
 use derive_more::{Deref, From};
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, From)]
@@ -88,6 +88,91 @@ macro_rules! impl_base_currency {
 impl_base_currency_core!(Currency, &Currency);
 impl_base_currency!(Currency, &Currency);
 
+/// The placeholder address many aggregators and wallets use for the native currency.
+pub const NATIVE_CURRENCY_PLACEHOLDER: Address =
+    address!("EeeeeEeeeEeEeeEeEeEeeEEEeeeeEeeeeeeeEEeE");
+
+/// The conventions for representing the native currency of a chain by an address.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum NativeAddressConvention {
+    /// `address(0)`, as used by Uniswap v4.
+    Zero,
+
+    /// [`NATIVE_CURRENCY_PLACEHOLDER`], as used by many aggregators and wallets.
+    Placeholder,
+
+    /// The address of the wrapped native token, as returned by [`BaseCurrency::address`].
+    Wrapped,
+}
+
+impl NativeAddressConvention {
+    /// All conventions
+    pub const ALL: [Self; 3] = [Self::Zero, Self::Placeholder, Self::Wrapped];
+
+    /// Returns the address representing the native currency on the chain, if any.
+    #[inline]
+    #[must_use]
+    pub fn native_address(self, chain_id: ChainId) -> Option<Address> {
+        match self {
+            Self::Zero => Some(Address::ZERO),
+            Self::Placeholder => Some(NATIVE_CURRENCY_PLACEHOLDER),
+            Self::Wrapped => WETH9::on_chain(chain_id).map(|weth| weth.address),
+        }
+    }
+}
+
+impl Currency {
+    /// Returns the address of the currency, representing the native currency according to the
+    /// given convention.
+    ///
+    /// Tokens are always represented by their own address.
+    #[inline]
+    pub fn to_address(&self, convention: NativeAddressConvention) -> Result<Address, Error> {
+        match self {
+            Self::NativeCurrency(ether) => convention
+                .native_address(ether.chain_id)
+                .ok_or(Error::MissingWrappedNative(ether.chain_id)),
+            Self::Token(token) => Ok(token.address),
+        }
+    }
+
+    /// Creates a currency from an address, e.g. from an API response, recognizing the native
+    /// currency according to any of the given conventions.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id`: The chain ID of the currency
+    /// * `address`: The address of the currency
+    /// * `decimals`: The decimals of the currency if it is a token
+    /// * `conventions`: The conventions by which the address may represent the native currency
+    ///
+    /// Returns [`Error::ZeroChainId`] for chain ID 0 and the errors of [`TokenBuilder::build`] if
+    /// the address is a token.
+    #[inline]
+    pub fn from_address(
+        chain_id: ChainId,
+        address: Address,
+        decimals: u8,
+        conventions: &[NativeAddressConvention],
+    ) -> Result<Self, Error> {
+        if chain_id == 0 {
+            return Err(Error::ZeroChainId);
+        }
+        if conventions
+            .iter()
+            .any(|convention| convention.native_address(chain_id) == Some(address))
+        {
+            Ok(Self::NativeCurrency(Ether::on_chain(chain_id)))
+        } else {
+            Token::builder(chain_id)
+                .address(address)
+                .decimals(decimals)
+                .build()
+                .map(Self::Token)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -126,4 +211,90 @@ mod tests {
     fn equals_token0_is_equal_to_another_token0() {
         assert!(TOKEN0.equals(&token!(1, ADDRESS_ZERO, 18, "symbol", "name")));
     }
+
+    #[test]
+    fn to_address_follows_convention() {
+        let ether = Currency::NativeCurrency(Ether::on_chain(1));
+        assert_eq!(
+            ether.to_address(NativeAddressConvention::Zero),
+            Ok(Address::ZERO)
+        );
+        assert_eq!(
+            ether.to_address(NativeAddressConvention::Placeholder),
+            Ok(NATIVE_CURRENCY_PLACEHOLDER)
+        );
+        assert_eq!(
+            ether.to_address(NativeAddressConvention::Wrapped),
+            Ok(ether.address())
+        );
+        assert_eq!(
+            Currency::NativeCurrency(Ether::on_chain(12345))
+                .to_address(NativeAddressConvention::Wrapped),
+            Err(Error::MissingWrappedNative(12345))
+        );
+        let token = Currency::Token(TOKEN1.clone());
+        for convention in NativeAddressConvention::ALL {
+            assert_eq!(token.to_address(convention), Ok(TOKEN1.address));
+        }
+    }
+
+    #[test]
+    fn from_address_recognizes_native() {
+        let weth = WETH9::on_chain(1).unwrap().address;
+        for address in [Address::ZERO, NATIVE_CURRENCY_PLACEHOLDER, weth] {
+            let currency = Currency::from_address(1, address, 18, &NativeAddressConvention::ALL);
+            assert_eq!(currency, Ok(Currency::NativeCurrency(Ether::on_chain(1))));
+        }
+        assert!(Currency::from_address(
+            1,
+            weth,
+            18,
+            &[
+                NativeAddressConvention::Zero,
+                NativeAddressConvention::Placeholder
+            ]
+        )
+        .unwrap()
+        .is_token());
+        assert!(Currency::from_address(
+            8453,
+            NATIVE_CURRENCY_PLACEHOLDER,
+            18,
+            &NativeAddressConvention::ALL
+        )
+        .unwrap()
+        .equals(&Ether::on_chain(8453)));
+        let token = Currency::from_address(1, TOKEN1.address, 18, &NativeAddressConvention::ALL);
+        assert!(token.unwrap().equals(&TOKEN1.clone()));
+    }
+
+    #[test]
+    fn from_address_rejects_invalid_input() {
+        assert_eq!(
+            Currency::from_address(0, TOKEN1.address, 18, &NativeAddressConvention::ALL),
+            Err(Error::ZeroChainId)
+        );
+        assert_eq!(
+            Currency::from_address(0, Address::ZERO, 18, &NativeAddressConvention::ALL),
+            Err(Error::ZeroChainId)
+        );
+        assert_eq!(
+            Currency::from_address(1, Address::ZERO, 18, &[]),
+            Err(Error::ZeroAddress)
+        );
+    }
+
+    #[test]
+    fn round_trip_through_conventions() {
+        for chain_id in [1, 10, 137, 8453, 42161] {
+            let ether = Currency::NativeCurrency(Ether::on_chain(chain_id));
+            for convention in NativeAddressConvention::ALL {
+                let address = ether.to_address(convention).unwrap();
+                assert_eq!(
+                    Currency::from_address(chain_id, address, 18, &[convention]),
+                    Ok(ether.clone())
+                );
+            }
+        }
+    }
 }
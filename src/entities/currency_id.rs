@@ -0,0 +1,117 @@
+use crate::prelude::*;
+use alloy_primitives::ChainId;
+
+/// Identity of a currency, consistent with [`BaseCurrency::equals`].
+///
+/// Unlike the currencies themselves, whose `Hash` and `Eq` implementations cover every field
+/// including the symbol and name, two currencies that are [`BaseCurrency::equals`] always have the
+/// same [`CurrencyId`]. This makes it suitable as a map key.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CurrencyId {
+    /// The native currency of the chain
+    Native(ChainId),
+    /// A token identified by its chain and address
+    Token(ChainId, Address),
+}
+
+impl CurrencyId {
+    /// Returns the identity of the given currency.
+    #[inline]
+    pub fn of(currency: &impl BaseCurrency) -> Self {
+        if currency.is_native() {
+            Self::Native(currency.chain_id())
+        } else {
+            Self::Token(currency.chain_id(), currency.address())
+        }
+    }
+
+    /// The chain ID of the identified currency
+    #[inline]
+    #[must_use]
+    pub const fn chain_id(&self) -> ChainId {
+        match self {
+            Self::Native(chain_id) | Self::Token(chain_id, _) => *chain_id,
+        }
+    }
+
+    /// Returns whether the identified currency is native to its chain
+    #[inline]
+    #[must_use]
+    pub const fn is_native(&self) -> bool {
+        matches!(self, Self::Native(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token;
+
+    const ADDRESS_ONE: &str = "0x0000000000000000000000000000000000000001";
+    const ADDRESS_TWO: &str = "0x0000000000000000000000000000000000000002";
+
+    #[test]
+    fn test_same_id_for_equal_tokens() {
+        let token = token!(1, ADDRESS_ONE, 18, "Test", "Te");
+        let token_1 = token!(1, ADDRESS_ONE, 9, "Other", "Ot");
+        assert!(token.equals(&token_1));
+        assert_ne!(token, token_1);
+        assert_eq!(token.currency_id(), token_1.currency_id());
+        assert_eq!(
+            Currency::Token(token.clone()).currency_id(),
+            token.currency_id()
+        );
+    }
+
+    #[test]
+    fn test_different_ids() {
+        let token = token!(1, ADDRESS_ONE, 18);
+        assert_ne!(
+            token.currency_id(),
+            token!(2, ADDRESS_ONE, 18).currency_id()
+        );
+        assert_ne!(
+            token.currency_id(),
+            token!(1, ADDRESS_TWO, 18).currency_id()
+        );
+        assert_ne!(token.currency_id(), Ether::on_chain(1).currency_id());
+        assert_ne!(
+            Ether::on_chain(1).currency_id(),
+            Ether::on_chain(2).currency_id()
+        );
+    }
+
+    #[test]
+    fn test_native_id() {
+        let ether = Ether::on_chain(1);
+        assert_eq!(ether.currency_id(), CurrencyId::Native(1));
+        assert_eq!(
+            Currency::NativeCurrency(ether.clone()).currency_id(),
+            ether.currency_id()
+        );
+        // does not require a wrapped token
+        assert_eq!(
+            Ether::on_chain(12345).currency_id(),
+            CurrencyId::Native(12345)
+        );
+    }
+
+    #[test]
+    fn test_map_key() {
+        let mut map: HashMap<CurrencyId, i32> = HashMap::default();
+        map.insert(token!(1, ADDRESS_ONE, 18, "A").currency_id(), 1);
+        map.insert(token!(1, ADDRESS_ONE, 18, "B").currency_id(), 2);
+        map.insert(Ether::on_chain(1).currency_id(), 3);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&token!(1, ADDRESS_ONE, 18).currency_id()], 2);
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(CurrencyId::Native(1) < CurrencyId::Native(2));
+        assert!(CurrencyId::Native(2) < CurrencyId::Token(1, Address::ZERO));
+        assert!(
+            token!(1, ADDRESS_ONE, 18).currency_id() < token!(1, ADDRESS_TWO, 18).currency_id()
+        );
+    }
+}
@@ -0,0 +1,362 @@
+use crate::prelude::*;
+use crate::utils::CastFrom;
+use fastnum::I1024;
+
+/// Extra digits carried through intermediate steps so that rounding errors do not reach the
+/// requested precision.
+const GUARD_DIGITS: u8 = 6;
+
+/// Fixed point number with `scale` as one, used for compounding.
+#[derive(Clone, Copy, Debug)]
+struct Fixed {
+    value: I1024,
+    scale: I1024,
+}
+
+impl Fixed {
+    /// Rounds a rational number to the fixed point scale.
+    #[inline]
+    fn from_ratio(numerator: BigInt, denominator: BigInt, scale: I1024) -> Result<Self, Error> {
+        Ok(Self {
+            value: div_round(
+                I1024::cast_from(numerator)
+                    .checked_mul(scale)
+                    .ok_or(Error::UintOverflow)?,
+                I1024::cast_from(denominator),
+            ),
+            scale,
+        })
+    }
+
+    /// Returns the growth factor `1 + r` of a return `r`.
+    #[inline]
+    fn growth(r: &Percent, scale: I1024) -> Result<Self, Error> {
+        let growth = Self::from_ratio(r.numerator + r.denominator, r.denominator, scale)?;
+        if growth.value < I1024::ZERO {
+            return Err(Error::Negative);
+        }
+        Ok(growth)
+    }
+
+    #[inline]
+    fn mul(self, other: Self) -> Result<Self, Error> {
+        let product = self
+            .value
+            .checked_mul(other.value)
+            .ok_or(Error::UintOverflow)?;
+        Ok(Self {
+            value: div_round(product, self.scale),
+            scale: self.scale,
+        })
+    }
+
+    #[inline]
+    fn div(self, other: Self) -> Result<Self, Error> {
+        if other.value == I1024::ZERO {
+            return Err(Error::DivisionByZero);
+        }
+        let numerator = self
+            .value
+            .checked_mul(self.scale)
+            .ok_or(Error::UintOverflow)?;
+        Ok(Self {
+            value: div_round(numerator, other.value),
+            scale: self.scale,
+        })
+    }
+
+    #[inline]
+    fn pow(self, mut exponent: u64) -> Result<Self, Error> {
+        let mut base = self;
+        let mut result = Self {
+            value: self.scale,
+            scale: self.scale,
+        };
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(base)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul(base)?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Computes the `n`th root with Newton's method.
+    #[inline]
+    fn root(self, n: u64) -> Result<Self, Error> {
+        if n == 1 || self.value == I1024::ZERO {
+            return Ok(self);
+        }
+        let n_fixed = I1024::cast_from(n);
+        // by Bernoulli's inequality `1 + (a - 1) / n` is never below the root, so the iterations
+        // decrease monotonically towards it
+        let mut x = Self {
+            value: self.scale + (self.value - self.scale) / n_fixed,
+            scale: self.scale,
+        };
+        loop {
+            let quotient = self.div(x.pow(n - 1)?)?;
+            let next = (x.value * (n_fixed - I1024::ONE) + quotient.value) / n_fixed;
+            if next >= x.value {
+                return Ok(x);
+            }
+            x.value = next;
+        }
+    }
+
+    /// Converts the growth factor `1 + r` back to the return `r` with `precision` decimal digits.
+    #[inline]
+    fn into_return(self, precision: u8) -> Result<Percent, Error> {
+        let unit = I1024::from(10).pow(precision as u32);
+        let value = div_round(self.value, self.scale / unit) - unit;
+        if value > I1024::cast_from(BigInt::MAX) {
+            return Err(Error::UintOverflow);
+        }
+        Ok(Percent::new(
+            BigInt::cast_from(value),
+            BigInt::cast_from(unit),
+        ))
+    }
+}
+
+/// Divides rounding halfway cases away from zero, for a positive divisor.
+#[inline]
+fn div_round(numerator: I1024, denominator: I1024) -> I1024 {
+    let half = denominator / I1024::from(2);
+    if numerator < I1024::ZERO {
+        (numerator - half) / denominator
+    } else {
+        (numerator + half) / denominator
+    }
+}
+
+/// Returns the fixed point scale for results with `precision` decimal digits.
+#[inline]
+fn scale(precision: u8) -> Result<I1024, Error> {
+    if precision > Percent::MAX_COMPOUNDING_PRECISION {
+        return Err(Error::out_of_range(
+            "precision",
+            precision as u64,
+            Percent::MAX_COMPOUNDING_PRECISION as u64,
+        ));
+    }
+    Ok(I1024::from(10).pow((precision + GUARD_DIGITS) as u32))
+}
+
+impl Percent {
+    /// The maximum number of decimal digits of the results of compounding helpers.
+    pub const MAX_COMPOUNDING_PRECISION: u8 = 60;
+
+    /// Compounds the rate over `periods` periods, i.e. returns `(1 + r / periods)^periods - 1`.
+    ///
+    /// Like all compounding helpers, the result is a fraction with `precision` decimal digits,
+    /// rounding halfway cases away from zero. Results with fewer digits are exact.
+    ///
+    /// # Arguments
+    ///
+    /// * `periods`: the number of compounding periods, which must be positive
+    /// * `precision`: the number of decimal digits of the result, at most
+    ///   [`Percent::MAX_COMPOUNDING_PRECISION`]
+    #[inline]
+    pub fn compound(&self, periods: u64, precision: u8) -> Result<Self, Error> {
+        if periods == 0 {
+            return Err(Error::ZeroPeriods);
+        }
+        let scale = scale(precision)?;
+        let growth = Fixed::from_ratio(
+            self.numerator + self.denominator * BigInt::cast_from(periods),
+            self.denominator * BigInt::cast_from(periods),
+            scale,
+        )?;
+        if growth.value < I1024::ZERO {
+            return Err(Error::Negative);
+        }
+        growth.pow(periods)?.into_return(precision)
+    }
+
+    /// Converts an APR compounded `periods_per_year` times a year into an APY.
+    ///
+    /// This is [`Percent::compound`] over one year.
+    #[inline]
+    pub fn apr_to_apy(&self, periods_per_year: u64, precision: u8) -> Result<Self, Error> {
+        self.compound(periods_per_year, precision)
+    }
+
+    /// Converts an APY into the APR which, compounded `periods_per_year` times a year, yields it,
+    /// i.e. `periods_per_year * ((1 + apy)^(1 / periods_per_year) - 1)`.
+    #[inline]
+    pub fn apy_to_apr(&self, periods_per_year: u64, precision: u8) -> Result<Self, Error> {
+        if periods_per_year == 0 {
+            return Err(Error::ZeroPeriods);
+        }
+        let scale = scale(precision)?;
+        let root = Fixed::growth(self, scale)?.root(periods_per_year)?;
+        Fixed {
+            value: (root.value - scale) * I1024::cast_from(periods_per_year) + scale,
+            scale,
+        }
+        .into_return(precision)
+    }
+
+    /// Annualizes the return over a period, i.e. returns `(1 + r)^(year / period) - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `period`: the length of the period of the return
+    /// * `year`: the length of a year, in the same unit as `period`
+    /// * `precision`: the number of decimal digits of the result
+    #[inline]
+    pub fn annualize(&self, period: u64, year: u64, precision: u8) -> Result<Self, Error> {
+        if period == 0 || year == 0 {
+            return Err(Error::ZeroPeriods);
+        }
+        let gcd = num_integer::gcd(period, year);
+        let scale = scale(precision)?;
+        Fixed::growth(self, scale)?
+            .pow(year / gcd)?
+            .root(period / gcd)?
+            .into_return(precision)
+    }
+
+    /// Returns the geometric average of a series of returns, i.e. the return which, repeated
+    /// over every period, compounds to the same total return.
+    #[inline]
+    pub fn geometric_mean(returns: &[Self], precision: u8) -> Result<Self, Error> {
+        if returns.is_empty() {
+            return Err(Error::Empty);
+        }
+        let scale = scale(precision)?;
+        let mut product = Fixed {
+            value: scale,
+            scale,
+        };
+        for r in returns {
+            product = product.mul(Fixed::growth(r, scale)?)?;
+        }
+        product.root(returns.len() as u64)?.into_return(precision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compound_exact() {
+        // 1.1^2 = 1.21
+        assert_eq!(
+            Percent::new(20, 100).compound(2, 18).unwrap(),
+            Percent::new(21, 100)
+        );
+        // 1.01^12 has 24 decimals
+        assert_eq!(
+            Percent::new(12, 100).compound(12, 24).unwrap(),
+            Percent::new(
+                BigInt::from(101).pow(12) - BigInt::from(10).pow(24),
+                BigInt::from(10).pow(24)
+            )
+        );
+        assert_eq!(
+            Percent::new(5, 100).compound(1, 18).unwrap(),
+            Percent::new(5, 100)
+        );
+    }
+
+    #[test]
+    fn test_apr_to_apy() {
+        // e^0.05 - 1 = 0.051271096...
+        let apy = Percent::new(5, 100).apr_to_apy(365, 8).unwrap();
+        assert_eq!(apy.to_fixed(6, None), "5.126750");
+        let apy = Percent::new(5, 100).apr_to_apy(31_536_000, 12).unwrap();
+        assert_eq!(apy.to_fixed(6, None), "5.127110");
+    }
+
+    #[test]
+    fn test_apy_to_apr() {
+        let apr = Percent::new(5, 100);
+        let apy = apr.apr_to_apy(365, 30).unwrap();
+        assert_eq!(apy.apy_to_apr(365, 20).unwrap(), apr);
+        // 1.21^(1/2) = 1.1
+        assert_eq!(
+            Percent::new(21, 100).apy_to_apr(2, 18).unwrap(),
+            Percent::new(20, 100)
+        );
+        assert_eq!(
+            Percent::new(21, 100).apy_to_apr(1, 18).unwrap(),
+            Percent::new(21, 100)
+        );
+    }
+
+    #[test]
+    fn test_annualize() {
+        // 1% per month
+        assert_eq!(
+            Percent::new(1, 100).annualize(1, 12, 24).unwrap(),
+            Percent::new(12, 100).compound(12, 24).unwrap()
+        );
+        // 21% over two years
+        assert_eq!(
+            Percent::new(21, 100).annualize(730, 365, 18).unwrap(),
+            Percent::new(10, 100)
+        );
+        // 0.1% per week
+        assert_eq!(
+            Percent::new(1, 1000)
+                .annualize(7, 365, 10)
+                .unwrap()
+                .to_fixed(6, None),
+            "5.349879"
+        );
+    }
+
+    #[test]
+    fn test_geometric_mean() {
+        // 1.21 * 1.0 * 0.9 = 1.089 over three periods
+        let mean = Percent::geometric_mean(
+            &[
+                Percent::new(21, 100),
+                Percent::new(0, 1),
+                Percent::new(-10, 100),
+            ],
+            12,
+        )
+        .unwrap();
+        assert_eq!(mean.to_fixed(6, None), "2.882765");
+        assert_eq!(
+            Percent::geometric_mean(&[Percent::new(10, 100), Percent::new(10, 100)], 18).unwrap(),
+            Percent::new(10, 100)
+        );
+        assert_eq!(
+            Percent::geometric_mean(&[Percent::new(-100, 100)], 18).unwrap(),
+            Percent::new(-1, 1)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(Percent::geometric_mean(&[], 18), Err(Error::Empty));
+        assert_eq!(
+            Percent::new(5, 100).compound(0, 18),
+            Err(Error::ZeroPeriods)
+        );
+        assert_eq!(
+            Percent::new(5, 100).compound(12, 61),
+            Err(Error::out_of_range("precision", 61, 60))
+        );
+        assert_eq!(
+            Percent::new(-101, 100).annualize(1, 12, 18),
+            Err(Error::Negative)
+        );
+        assert_eq!(
+            Percent::new(1000, 1).compound(1_000_000, 18),
+            Err(Error::UintOverflow)
+        );
+        let scale = I1024::from(10).pow(18);
+        let one = Fixed::from_ratio(BigInt::ONE, BigInt::ONE, scale).unwrap();
+        let zero = Fixed::from_ratio(BigInt::ZERO, BigInt::ONE, scale).unwrap();
+        assert_eq!(one.div(zero).unwrap_err(), Error::DivisionByZero);
+    }
+}
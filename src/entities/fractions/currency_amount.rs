@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use alloc::string::ToString;
+use core::{borrow::Borrow, cmp::Ordering};
 use fastnum::i512;
 
 /// Currency amount struct that represents a rational amount of a currency
@@ -24,6 +25,9 @@ impl<T: BaseCurrency> CurrencyAmount<T> {
     ) -> Result<Self, Error> {
         let numerator = numerator.into();
         let denominator = denominator.into();
+        if denominator == BigInt::ZERO {
+            return Err(Error::DivisionByZero);
+        }
         // Ensure the amount does not exceed MAX_UINT256
         if numerator.div_floor(denominator) > MAX_UINT256 {
             return Err(Error::UintOverflow);
@@ -69,6 +73,9 @@ impl<T: BaseCurrency> CurrencyAmount<T> {
     /// Division of currency amount by another fractional amount
     #[inline]
     pub fn divide<M: Clone>(&self, other: &impl FractionBase<M>) -> Result<Self, Error> {
+        if other.numerator() == BigInt::ZERO {
+            return Err(Error::DivisionByZero);
+        }
         let divided = self.as_fraction() / other.as_fraction();
         Self::from_fractional_amount(
             self.currency.clone(),
@@ -131,7 +138,10 @@ impl<T: BaseCurrency> CurrencyAmount<T> {
         rounding: Option<Rounding>,
     ) -> Result<String, Error> {
         if decimal_places > self.currency.decimals() {
-            return Err(Error::Invalid("DECIMALS"));
+            return Err(Error::DecimalsOutOfRange {
+                decimals: decimal_places,
+                max: self.currency.decimals(),
+            });
         }
         Ok(
             (self.as_fraction() / Fraction::new(self.decimal_scale, 1)).to_fixed(
@@ -160,6 +170,59 @@ impl<T: BaseCurrency> CurrencyAmount<T> {
             self.denominator(),
         )
     }
+
+    /// Sums amounts of the same currency.
+    ///
+    /// Fails with [`Error::CurrencyMismatch`] if the currencies differ, [`Error::UintOverflow`] if
+    /// the sum exceeds `MAX_UINT256`, and [`Error::Empty`] if there are no amounts.
+    #[inline]
+    pub fn try_sum<A: Borrow<Self>>(amounts: impl IntoIterator<Item = A>) -> Result<Self, Error> {
+        let mut amounts = amounts.into_iter();
+        let first = amounts.next().ok_or(Error::Empty)?;
+        let first = first.borrow();
+        let mut sum = first.as_fraction();
+        for amount in amounts {
+            let amount = amount.borrow();
+            if !amount.currency.equals(&first.currency) {
+                return Err(Error::CurrencyMismatch);
+            }
+            sum = sum + amount.as_fraction();
+        }
+        Self::from_fractional_amount(first.currency.clone(), sum.numerator, sum.denominator)
+    }
+
+    /// Returns the smallest of amounts of the same currency.
+    #[inline]
+    pub fn try_min(amounts: &[Self]) -> Result<Self, Error> {
+        Self::try_extreme(amounts, Ordering::Less)
+    }
+
+    /// Returns the largest of amounts of the same currency.
+    #[inline]
+    pub fn try_max(amounts: &[Self]) -> Result<Self, Error> {
+        Self::try_extreme(amounts, Ordering::Greater)
+    }
+
+    /// Returns the exact arithmetic mean of amounts of the same currency.
+    #[inline]
+    pub fn try_average(amounts: &[Self]) -> Result<Self, Error> {
+        Self::try_sum(amounts)?.divide(&Fraction::new(BigInt::cast_from(amounts.len() as u64), 1))
+    }
+
+    #[inline]
+    fn try_extreme(amounts: &[Self], ordering: Ordering) -> Result<Self, Error> {
+        let (first, rest) = amounts.split_first().ok_or(Error::Empty)?;
+        let mut extreme = first;
+        for amount in rest {
+            if !amount.currency.equals(&first.currency) {
+                return Err(Error::CurrencyMismatch);
+            }
+            if amount.as_fraction().cmp(&extreme.as_fraction()) == ordering {
+                extreme = amount;
+            }
+        }
+        Ok(extreme.clone())
+    }
 }
 
 #[cfg(test)]
@@ -178,6 +241,63 @@ mod tests {
     }
 
     // Unit tests
+    #[test]
+    fn test_try_sum() {
+        let amounts = [
+            CurrencyAmount::from_raw_amount(TOKEN18.clone(), 100).unwrap(),
+            CurrencyAmount::from_fractional_amount(TOKEN18.clone(), 1, 2).unwrap(),
+            CurrencyAmount::from_raw_amount(TOKEN18.clone(), 50).unwrap(),
+        ];
+        assert_eq!(
+            CurrencyAmount::try_sum(&amounts).unwrap(),
+            CurrencyAmount::from_fractional_amount(TOKEN18.clone(), 301, 2).unwrap()
+        );
+        assert_eq!(
+            CurrencyAmount::try_sum(amounts.clone()).unwrap().quotient(),
+            BigInt::from(150)
+        );
+        assert_eq!(CurrencyAmount::<Token>::try_sum(&[]), Err(Error::Empty));
+        let other = token!(1, "0x0000000000000000000000000000000000000002", 18);
+        assert_eq!(
+            CurrencyAmount::try_sum([
+                amounts[0].clone(),
+                CurrencyAmount::from_raw_amount(other, 1).unwrap()
+            ]),
+            Err(Error::CurrencyMismatch)
+        );
+        assert_eq!(
+            CurrencyAmount::try_sum([
+                CurrencyAmount::from_raw_amount(TOKEN18.clone(), MAX_UINT256).unwrap(),
+                amounts[0].clone()
+            ]),
+            Err(Error::UintOverflow)
+        );
+    }
+
+    #[test]
+    fn test_try_min_max_average() {
+        let amounts = [
+            CurrencyAmount::from_raw_amount(TOKEN18.clone(), 100).unwrap(),
+            CurrencyAmount::from_raw_amount(TOKEN18.clone(), 20).unwrap(),
+            CurrencyAmount::from_raw_amount(TOKEN18.clone(), 50).unwrap(),
+        ];
+        assert_eq!(CurrencyAmount::try_min(&amounts).unwrap(), amounts[1]);
+        assert_eq!(CurrencyAmount::try_max(&amounts).unwrap(), amounts[0]);
+        assert_eq!(
+            CurrencyAmount::try_average(&amounts).unwrap(),
+            CurrencyAmount::from_fractional_amount(TOKEN18.clone(), 170, 3).unwrap()
+        );
+        assert_eq!(CurrencyAmount::<Token>::try_max(&[]), Err(Error::Empty));
+        let other = token!(1, "0x0000000000000000000000000000000000000002", 18);
+        assert_eq!(
+            CurrencyAmount::try_min(&[
+                amounts[0].clone(),
+                CurrencyAmount::from_raw_amount(other, 1).unwrap()
+            ]),
+            Err(Error::CurrencyMismatch)
+        );
+    }
+
     #[test]
     fn test_constructor() {
         let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 100).unwrap();
@@ -222,6 +342,19 @@ mod tests {
         assert!(_w.is_ok(), "AMOUNT");
     }
 
+    #[test]
+    fn test_division_by_zero() {
+        assert_eq!(
+            CurrencyAmount::from_fractional_amount(TOKEN18.clone(), 1, 0),
+            Err(Error::DivisionByZero)
+        );
+        let amount = CurrencyAmount::from_raw_amount(TOKEN18.clone(), 1).unwrap();
+        assert_eq!(
+            amount.divide(&Fraction::new(0, 1)),
+            Err(Error::DivisionByZero)
+        );
+    }
+
     #[test]
     fn test_token_amount_numerator_gt_uint256() {
         let numerator: BigInt = MAX_UINT256 + BigInt::from(2);
@@ -232,8 +365,13 @@ mod tests {
     #[test]
     fn to_fixed_decimals_exceeds_currency_decimals() {
         let amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 1000).unwrap();
-        let _w = amount.to_fixed(3, None);
-        assert!(_w.is_err(), "DECIMALS");
+        assert_eq!(
+            amount.to_fixed(3, None),
+            Err(Error::DecimalsOutOfRange {
+                decimals: 3,
+                max: 0
+            })
+        );
     }
 
     #[test]
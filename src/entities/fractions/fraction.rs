@@ -1,9 +1,10 @@
 use crate::prelude::*;
+use crate::utils::CastFrom;
 use alloc::string::ToString;
-use bnum::cast::CastFrom;
 use core::{
     cmp::Ordering,
     hash::{Hash, Hasher},
+    iter::{Product, Sum},
     ops::{Add, Div, Mul, Sub},
 };
 use derive_more::Deref;
@@ -53,6 +54,19 @@ impl Fraction {
     pub fn new(numerator: impl Into<BigInt>, denominator: impl Into<BigInt>) -> Self {
         FractionBase::new(numerator, denominator, ())
     }
+
+    /// Creates a new `Fraction`, or returns [`Error::DivisionByZero`] if the denominator is zero.
+    #[inline]
+    pub fn try_new(
+        numerator: impl Into<BigInt>,
+        denominator: impl Into<BigInt>,
+    ) -> Result<Self, Error> {
+        let denominator = denominator.into();
+        if denominator == BigInt::ZERO {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Self::new(numerator, denominator))
+    }
 }
 
 /// Function to convert the custom Rounding enum to [`RoundingMode`]
@@ -128,7 +142,7 @@ pub trait FractionBase<M: Clone>: Sized {
         rounding: Option<Rounding>,
     ) -> Result<String, Error> {
         if significant_digits == 0 {
-            return Err(Error::Invalid("SIGNIFICANT_DIGITS"));
+            return Err(Error::ZeroSignificantDigits);
         }
         let rounding_strategy = to_rounding_strategy(rounding.unwrap_or_default());
         let quotient = self.to_decimal().with_rounding_mode(rounding_strategy);
@@ -314,10 +328,56 @@ macro_rules! impl_div {
 impl_div!(Div, div, Self);
 impl_div!(Div, div, &Self);
 
+macro_rules! impl_sum_product {
+    ($($item:ty),*) => {
+        $(
+            impl<'a, M: Clone + Default + 'a> Sum<$item> for FractionLike<M> {
+                #[inline]
+                fn sum<I: Iterator<Item = $item>>(iter: I) -> Self {
+                    iter.fold(Self::default(), |sum, item| sum + item)
+                }
+            }
+
+            impl<'a, M: Clone + Default + 'a> Product<$item> for FractionLike<M> {
+                #[inline]
+                fn product<I: Iterator<Item = $item>>(iter: I) -> Self {
+                    iter.fold(FractionBase::new(1, 1, M::default()), |product, item| {
+                        product * item
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_sum_product!(Self, &'a Self);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_sum_and_product() {
+        let fractions = [
+            Fraction::new(1, 2),
+            Fraction::new(1, 3),
+            Fraction::new(1, 6),
+        ];
+        assert_eq!(fractions.iter().sum::<Fraction>(), Fraction::new(1, 1));
+        assert_eq!(
+            fractions.into_iter().product::<Fraction>(),
+            Fraction::new(1, 36)
+        );
+        assert_eq!(
+            core::iter::empty::<Fraction>().sum::<Fraction>(),
+            Fraction::new(0, 1)
+        );
+        assert_eq!(
+            core::iter::empty::<Fraction>().product::<Fraction>(),
+            Fraction::new(1, 1)
+        );
+    }
+
     #[test]
     fn test_quotient() {
         assert_eq!(Fraction::new(8, 3).quotient(), BigInt::from(2));
@@ -417,6 +477,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_new() {
+        assert_eq!(Fraction::try_new(1, 2), Ok(Fraction::new(1, 2)));
+        assert_eq!(Fraction::try_new(1, 0), Err(Error::DivisionByZero));
+        assert_eq!(
+            Fraction::new(1, 3).to_significant(0, None),
+            Err(Error::ZeroSignificantDigits)
+        );
+    }
+
     #[test]
     fn test_as_faction() {
         let f = Fraction::new(1, 2);
@@ -1,9 +1,12 @@
+pub mod compounding;
 pub mod currency_amount;
 pub mod fraction;
 pub mod percent;
 pub mod price;
+pub mod slippage_tolerance;
 
 pub use currency_amount::*;
 pub use fraction::*;
 pub use percent::*;
 pub use price::*;
+pub use slippage_tolerance::*;
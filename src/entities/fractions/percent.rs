@@ -1,9 +1,5 @@
 use crate::prelude::*;
-use lazy_static::lazy_static;
-
-lazy_static! {
-    static ref ONE_HUNDRED: Fraction = Fraction::new(100, 1);
-}
+use core::str::FromStr;
 
 /// Unit struct to distinguish between a fraction and a percent
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
@@ -27,8 +23,7 @@ impl Percent {
         significant_digits: u8,
         rounding: Option<Rounding>,
     ) -> Result<String, Error> {
-        (self.as_fraction() * ONE_HUNDRED.as_fraction())
-            .to_significant(significant_digits, rounding)
+        (self.as_fraction() * Fraction::new(100, 1)).to_significant(significant_digits, rounding)
     }
 
     /// Converts the [`Percent`] to a string with a fixed number of decimal places and rounding
@@ -36,8 +31,161 @@ impl Percent {
     #[inline]
     #[must_use]
     pub fn to_fixed(&self, decimal_places: u8, rounding: Option<Rounding>) -> String {
-        (self.as_fraction() * ONE_HUNDRED.as_fraction()).to_fixed(decimal_places, rounding)
+        (self.as_fraction() * Fraction::new(100, 1)).to_fixed(decimal_places, rounding)
+    }
+
+    /// Creates a [`Percent`] from basis points, i.e. hundredths of a percent.
+    #[inline]
+    pub fn from_bps(bps: impl Into<BigInt>) -> Self {
+        Self::new(bps, 10_000)
+    }
+
+    /// Creates a [`Percent`] from pips, i.e. hundredths of a basis point, the unit of Uniswap v3
+    /// and v4 fees.
+    #[inline]
+    pub fn from_pips(pips: impl Into<BigInt>) -> Self {
+        Self::new(pips, 1_000_000)
+    }
+
+    /// Converts the [`Percent`] to a whole number of basis points with the given rounding.
+    #[inline]
+    #[must_use]
+    pub fn to_bps(&self, rounding: Rounding) -> BigInt {
+        round(
+            self.numerator * BigInt::from(10_000),
+            self.denominator,
+            rounding,
+        )
+    }
+
+    /// Converts the [`Percent`] to a whole number of pips with the given rounding.
+    #[inline]
+    #[must_use]
+    pub fn to_pips(&self, rounding: Rounding) -> BigInt {
+        round(
+            self.numerator * BigInt::from(1_000_000),
+            self.denominator,
+            rounding,
+        )
+    }
+
+    /// Checks that the [`Percent`] is between 0% and 100%, naming it `what` in the error.
+    #[inline]
+    pub(crate) fn check_unit(&self, what: &'static str) -> Result<(), Error> {
+        if self.denominator == BigInt::ZERO {
+            Err(Error::DivisionByZero)
+        } else if *self < Self::new(0, 1) {
+            Err(Error::Negative)
+        } else if *self > Self::new(1, 1) {
+            Err(Error::OutOfRange {
+                what,
+                value: self.to_decimal().resize(),
+                max: fastnum::D256::ONE,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Divides `numerator` by `denominator`, rounding the quotient to an integer.
+#[inline]
+fn round(numerator: BigInt, denominator: BigInt, rounding: Rounding) -> BigInt {
+    let (numerator, denominator) = if denominator < BigInt::ZERO {
+        (-numerator, -denominator)
+    } else {
+        (numerator, denominator)
+    };
+    let floor = numerator.div_floor(denominator);
+    let remainder = numerator - floor * denominator;
+    if remainder == BigInt::ZERO {
+        return floor;
+    }
+    let round_up = match rounding {
+        // towards zero
+        Rounding::RoundDown => numerator < BigInt::ZERO,
+        // halfway cases away from zero
+        Rounding::RoundHalfUp => {
+            let twice = remainder * BigInt::from(2);
+            twice > denominator || (twice == denominator && numerator > BigInt::ZERO)
+        }
+        // away from zero
+        Rounding::RoundUp => numerator > BigInt::ZERO,
+    };
+    if round_up {
+        floor + BigInt::ONE
+    } else {
+        floor
+    }
+}
+
+impl From<FeeAmount> for Percent {
+    #[inline]
+    fn from(fee: FeeAmount) -> Self {
+        Self::from_pips(fee.pips())
+    }
+}
+
+impl FromStr for Percent {
+    type Err = Error;
+
+    /// Parses a percent such as `"0.3%"`, `"30bps"` or `"3000 pips"`.
+    ///
+    /// Fails with [`Error::Parse`] at the first character that is not part of a percent.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let start = s.len() - s.trim_start().len();
+        let s = s.trim();
+        let error = |position| Error::Parse {
+            what: "percent",
+            position: start + position,
+        };
+        let (number, scale) = if let Some(number) = s.strip_suffix('%') {
+            (number, 100)
+        } else if let Some(number) = s.strip_suffix("bps").or_else(|| s.strip_suffix("bp")) {
+            (number, 10_000)
+        } else if let Some(number) = s.strip_suffix("pips") {
+            (number, 1_000_000)
+        } else {
+            return Err(error(s.len()));
+        };
+        let (numerator, denominator) = parse_decimal(number.trim_end()).map_err(error)?;
+        Ok(Self::new(numerator, denominator * BigInt::from(scale)))
+    }
+}
+
+/// Parses a decimal number such as `"-0.05"` into an exact numerator and power of ten
+/// denominator, or returns the position of the first invalid character.
+#[inline]
+fn parse_decimal(s: &str) -> Result<(BigInt, BigInt), usize> {
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, s),
+    };
+    let offset = s.len() - digits.len();
+    let ten = BigInt::from(10);
+    let mut numerator = BigInt::ZERO;
+    let mut count = 0;
+    let mut decimals = None;
+    for (i, b) in digits.bytes().enumerate() {
+        match b {
+            b'.' if decimals.is_none() => decimals = Some(0),
+            // bound the number of digits so that the result fits
+            b'0'..=b'9' if count < 77 => {
+                numerator = numerator * ten + BigInt::from(b - b'0');
+                count += 1;
+                if let Some(decimals) = decimals.as_mut() {
+                    *decimals += 1;
+                }
+            }
+            _ => return Err(offset + i),
+        }
     }
+    if count == 0 {
+        return Err(s.len());
+    }
+    let numerator = if negative { -numerator } else { numerator };
+    Ok((numerator, ten.pow(decimals.unwrap_or(0))))
 }
 
 #[cfg(test)]
@@ -92,6 +240,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sum_and_product() {
+        let fees = [
+            Percent::from_bps(30),
+            Percent::from_bps(5),
+            Percent::from_bps(1),
+        ];
+        assert_eq!(fees.iter().sum::<Percent>(), Percent::from_bps(36));
+        assert_eq!(
+            fees.into_iter().product::<Percent>(),
+            Percent::new(150, 10_000_i64.pow(3))
+        );
+    }
+
     #[test]
     fn test_to_significant() {
         assert_eq!(
@@ -100,6 +262,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bps_and_pips() {
+        assert_eq!(Percent::from_bps(30), Percent::new(3, 1000));
+        assert_eq!(Percent::from_pips(3000), Percent::new(3, 1000));
+        assert_eq!(Percent::from(FeeAmount::LOW), Percent::from_bps(5));
+        assert_eq!(
+            Percent::from_pips(3000).to_bps(Rounding::RoundDown),
+            BigInt::from(30)
+        );
+        assert_eq!(
+            Percent::from_bps(30).to_pips(Rounding::RoundDown),
+            BigInt::from(3000)
+        );
+    }
+
+    #[test]
+    fn test_to_bps_rounding() {
+        let percent = Percent::from_pips(2550);
+        assert_eq!(percent.to_bps(Rounding::RoundDown), BigInt::from(25));
+        assert_eq!(percent.to_bps(Rounding::RoundHalfUp), BigInt::from(26));
+        assert_eq!(percent.to_bps(Rounding::RoundUp), BigInt::from(26));
+        let percent = Percent::from_pips(2549);
+        assert_eq!(percent.to_bps(Rounding::RoundHalfUp), BigInt::from(25));
+        let percent = Percent::from_pips(-2550);
+        assert_eq!(percent.to_bps(Rounding::RoundDown), BigInt::from(-25));
+        assert_eq!(percent.to_bps(Rounding::RoundHalfUp), BigInt::from(-26));
+        assert_eq!(percent.to_bps(Rounding::RoundUp), BigInt::from(-26));
+        let percent = Percent::new(1, -3);
+        assert_eq!(percent.to_pips(Rounding::RoundDown), BigInt::from(-333_333));
+        assert_eq!(percent.to_pips(Rounding::RoundUp), BigInt::from(-333_334));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("0.3%".parse::<Percent>().unwrap(), Percent::new(3, 1000));
+        assert_eq!("30bps".parse::<Percent>().unwrap(), Percent::new(3, 1000));
+        assert_eq!("30 bps".parse::<Percent>().unwrap(), Percent::new(3, 1000));
+        assert_eq!("1bp".parse::<Percent>().unwrap(), Percent::from_bps(1));
+        assert_eq!(
+            " 3000 pips ".parse::<Percent>().unwrap(),
+            Percent::new(3, 1000)
+        );
+        assert_eq!("100%".parse::<Percent>().unwrap(), Percent::new(1, 1));
+        assert_eq!(".5%".parse::<Percent>().unwrap(), Percent::new(5, 1000));
+        assert_eq!("-1.5%".parse::<Percent>().unwrap(), Percent::new(-15, 1000));
+        for (invalid, position) in [
+            ("", 0),
+            ("%", 0),
+            ("0.3", 3),
+            (" abc%", 1),
+            ("1.2.3%", 3),
+            ("+1%", 0),
+            ("1e3bps", 1),
+            ("0.3 %%", 3),
+        ] {
+            assert_eq!(
+                invalid.parse::<Percent>(),
+                Err(Error::Parse {
+                    what: "percent",
+                    position
+                })
+            );
+        }
+    }
+
     #[test]
     fn test_to_fixed() {
         assert_eq!(
@@ -0,0 +1,204 @@
+use crate::prelude::*;
+
+/// The maximum price movement a trade accepts, validated to be between 0% and 100%.
+///
+/// Bounds are rounded down to whole raw amounts, matching the Uniswap SDKs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlippageTolerance(Percent);
+
+impl SlippageTolerance {
+    /// Creates a new [`SlippageTolerance`], failing if the percent is negative or exceeds 100%.
+    #[inline]
+    pub fn new(percent: Percent) -> Result<Self, Error> {
+        percent.check_unit("slippage tolerance")?;
+        Ok(Self(percent))
+    }
+
+    /// Returns the tolerance as a [`Percent`].
+    #[inline]
+    #[must_use]
+    pub const fn percent(&self) -> &Percent {
+        &self.0
+    }
+
+    /// Returns the minimum amount that must be received for a trade to stay within the tolerance.
+    ///
+    /// For [`TradeType::ExactOutput`] the output is fixed and returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_type`: the type of the trade
+    /// * `amount_out`: the expected output amount of the trade
+    #[inline]
+    pub fn minimum_amount_out<T: BaseCurrency>(
+        &self,
+        trade_type: TradeType,
+        amount_out: &CurrencyAmount<T>,
+    ) -> Result<CurrencyAmount<T>, Error> {
+        match trade_type {
+            TradeType::ExactOutput => Ok(amount_out.clone()),
+            TradeType::ExactInput => {
+                let adjusted = amount_out.divide(&(Fraction::new(1, 1) + self.0.as_fraction()))?;
+                CurrencyAmount::from_raw_amount(amount_out.currency.clone(), adjusted.quotient())
+            }
+        }
+    }
+
+    /// Returns the maximum amount that may be spent for a trade to stay within the tolerance.
+    ///
+    /// For [`TradeType::ExactInput`] the input is fixed and returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_type`: the type of the trade
+    /// * `amount_in`: the expected input amount of the trade
+    #[inline]
+    pub fn maximum_amount_in<T: BaseCurrency>(
+        &self,
+        trade_type: TradeType,
+        amount_in: &CurrencyAmount<T>,
+    ) -> Result<CurrencyAmount<T>, Error> {
+        match trade_type {
+            TradeType::ExactInput => Ok(amount_in.clone()),
+            TradeType::ExactOutput => {
+                let adjusted = amount_in.multiply(&(Fraction::new(1, 1) + self.0.as_fraction()))?;
+                CurrencyAmount::from_raw_amount(amount_in.currency.clone(), adjusted.quotient())
+            }
+        }
+    }
+
+    /// Returns the worst price a trade may execute at within the tolerance, i.e. the maximum
+    /// amount in for the minimum amount out.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_type`: the type of the trade
+    /// * `amount_in`: the expected input amount of the trade
+    /// * `amount_out`: the expected output amount of the trade
+    #[inline]
+    pub fn worst_execution_price<TInput: BaseCurrency, TOutput: BaseCurrency>(
+        &self,
+        trade_type: TradeType,
+        amount_in: &CurrencyAmount<TInput>,
+        amount_out: &CurrencyAmount<TOutput>,
+    ) -> Result<Price<TInput, TOutput>, Error> {
+        Ok(Price::new(
+            amount_in.currency.clone(),
+            amount_out.currency.clone(),
+            self.maximum_amount_in(trade_type, amount_in)?.quotient(),
+            self.minimum_amount_out(trade_type, amount_out)?.quotient(),
+        ))
+    }
+}
+
+impl TryFrom<Percent> for SlippageTolerance {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(percent: Percent) -> Result<Self, Self::Error> {
+        Self::new(percent)
+    }
+}
+
+impl From<SlippageTolerance> for Percent {
+    #[inline]
+    fn from(tolerance: SlippageTolerance) -> Self {
+        tolerance.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token;
+    use fastnum::{dec256, D256};
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref TOKEN0: Token = token!(1, "0000000000000000000000000000000000000001", 18);
+        static ref TOKEN1: Token = token!(1, "0000000000000000000000000000000000000002", 6);
+        static ref HALF_PERCENT: SlippageTolerance =
+            SlippageTolerance::new(Percent::new(50, 10000)).unwrap();
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(SlippageTolerance::new(Percent::new(0, 1)).is_ok());
+        assert!(SlippageTolerance::new(Percent::new(1, 1)).is_ok());
+        assert_eq!(
+            SlippageTolerance::new(Percent::new(-1, 100)),
+            Err(Error::Negative)
+        );
+        assert_eq!(
+            SlippageTolerance::try_from(Percent::new(101, 100)),
+            Err(Error::OutOfRange {
+                what: "slippage tolerance",
+                value: dec256!(1.01),
+                max: D256::ONE,
+            })
+        );
+    }
+
+    #[test]
+    fn test_minimum_amount_out() {
+        let amount = CurrencyAmount::from_raw_amount(TOKEN1.clone(), 1_000_000).unwrap();
+        // 1_000_000 / 1.005 = 995_024.87
+        assert_eq!(
+            HALF_PERCENT
+                .minimum_amount_out(TradeType::ExactInput, &amount)
+                .unwrap()
+                .quotient(),
+            BigInt::from(995_024)
+        );
+        assert_eq!(
+            HALF_PERCENT
+                .minimum_amount_out(TradeType::ExactOutput, &amount)
+                .unwrap(),
+            amount
+        );
+    }
+
+    #[test]
+    fn test_maximum_amount_in() {
+        let amount = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 1_000_001).unwrap();
+        // 1_000_001 * 1.005 = 1_005_001.005
+        assert_eq!(
+            HALF_PERCENT
+                .maximum_amount_in(TradeType::ExactOutput, &amount)
+                .unwrap()
+                .quotient(),
+            BigInt::from(1_005_001)
+        );
+        assert_eq!(
+            HALF_PERCENT
+                .maximum_amount_in(TradeType::ExactInput, &amount)
+                .unwrap(),
+            amount
+        );
+    }
+
+    #[test]
+    fn test_worst_execution_price() {
+        let amount_in = CurrencyAmount::from_raw_amount(TOKEN0.clone(), 1000).unwrap();
+        let amount_out = CurrencyAmount::from_raw_amount(TOKEN1.clone(), 2000).unwrap();
+        let price = HALF_PERCENT
+            .worst_execution_price(TradeType::ExactInput, &amount_in, &amount_out)
+            .unwrap();
+        // 2000 / 1.005 = 1990.05
+        assert_eq!(price.numerator, BigInt::from(1990));
+        assert_eq!(price.denominator, BigInt::from(1000));
+
+        let price = HALF_PERCENT
+            .worst_execution_price(TradeType::ExactOutput, &amount_in, &amount_out)
+            .unwrap();
+        assert_eq!(price.numerator, BigInt::from(2000));
+        assert_eq!(price.denominator, BigInt::from(1005));
+
+        let zero = SlippageTolerance::new(Percent::new(0, 1)).unwrap();
+        assert_eq!(
+            zero.worst_execution_price(TradeType::ExactInput, &amount_in, &amount_out)
+                .unwrap(),
+            Price::from_currency_amounts(amount_in, amount_out)
+        );
+    }
+}
@@ -1,15 +1,33 @@
+pub mod arbitrage;
+pub mod balances;
 pub mod base_currency;
+pub mod canonical_asset;
 pub mod currency;
+pub mod currency_id;
 pub mod ether;
 pub mod fractions;
 pub mod native_currency;
+pub mod price_graph;
+pub mod route;
+pub mod swap_pool;
 pub mod token;
+pub mod trade;
+pub mod vault_share;
 pub mod weth9;
 
+pub use arbitrage::*;
+pub use balances::*;
 pub use base_currency::*;
+pub use canonical_asset::*;
 pub use currency::*;
+pub use currency_id::CurrencyId;
 pub use ether::Ether;
 pub use fractions::*;
 pub use native_currency::NativeCurrency;
+pub use price_graph::*;
+pub use route::Route;
+pub use swap_pool::SwapPool;
 pub use token::*;
-pub use weth9::WETH9;
+pub use trade::*;
+pub use vault_share::*;
+pub use weth9::*;
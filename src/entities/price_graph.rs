@@ -0,0 +1,397 @@
+use crate::prelude::*;
+use alloc::{collections::BTreeMap, vec};
+
+/// A directed edge of the [`PriceGraph`].
+#[derive(Clone, Debug)]
+struct Edge {
+    price: Price<Currency, Currency>,
+    /// Whether the price was added explicitly rather than inferred by inverting the reverse edge
+    explicit: bool,
+}
+
+/// A cross rate between two currencies found in a [`PriceGraph`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PricePath {
+    /// The price of the first currency of the path in terms of the last
+    pub price: Price<Currency, Currency>,
+    /// The currencies traversed, starting with the base and ending with the quote currency
+    pub path: Vec<Currency>,
+}
+
+/// A graph of prices between currencies, answering cross rates between any two connected
+/// currencies.
+///
+/// Every price added is also usable in reverse through [`Price::invert`], unless a price for the
+/// reverse direction is added explicitly, e.g. to model a bid/ask spread.
+#[derive(Clone, Debug, Default)]
+pub struct PriceGraph {
+    currencies: BTreeMap<CurrencyId, Currency>,
+    edges: BTreeMap<CurrencyId, BTreeMap<CurrencyId, Edge>>,
+}
+
+impl PriceGraph {
+    /// Creates an empty [`PriceGraph`].
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            currencies: BTreeMap::new(),
+            edges: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a price, replacing any previous price in the same direction.
+    ///
+    /// Fails with [`Error::EqualAddresses`] if the price is between a currency and itself,
+    /// [`Error::ZeroQuote`] if it is zero or undefined, and [`Error::Negative`] if it is negative.
+    #[inline]
+    pub fn add_price(&mut self, price: Price<Currency, Currency>) -> Result<(), Error> {
+        if price.base_currency.equals(&price.quote_currency) {
+            return Err(Error::EqualAddresses);
+        }
+        if price.numerator == BigInt::ZERO || price.denominator == BigInt::ZERO {
+            return Err(Error::ZeroQuote);
+        }
+        if (price.numerator < BigInt::ZERO) != (price.denominator < BigInt::ZERO) {
+            return Err(Error::Negative);
+        }
+        let base = price.base_currency.currency_id();
+        let quote = price.quote_currency.currency_id();
+        self.currencies
+            .entry(base)
+            .or_insert_with(|| price.base_currency.clone());
+        self.currencies
+            .entry(quote)
+            .or_insert_with(|| price.quote_currency.clone());
+        let reverse = self.edges.entry(quote).or_default();
+        if !reverse.get(&base).is_some_and(|edge| edge.explicit) {
+            reverse.insert(
+                base,
+                Edge {
+                    price: price.invert(),
+                    explicit: false,
+                },
+            );
+        }
+        self.edges.entry(base).or_default().insert(
+            quote,
+            Edge {
+                price,
+                explicit: true,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the currencies in the graph.
+    #[inline]
+    pub fn currencies(&self) -> impl Iterator<Item = &Currency> {
+        self.currencies.values()
+    }
+
+    /// Returns the currency with the given identity, if it is in the graph.
+    #[inline]
+    #[must_use]
+    pub fn currency(&self, id: &CurrencyId) -> Option<&Currency> {
+        self.currencies.get(id)
+    }
+
+    /// Returns all directed prices in the graph, including the inferred reverse prices.
+    #[inline]
+    pub fn prices(&self) -> impl Iterator<Item = &Price<Currency, Currency>> {
+        self.edges
+            .values()
+            .flat_map(|edges| edges.values().map(|edge| &edge.price))
+    }
+
+    /// Returns the direct price from one currency to another, if any.
+    #[inline]
+    pub fn price(
+        &self,
+        base: &impl BaseCurrency,
+        quote: &impl BaseCurrency,
+    ) -> Option<&Price<Currency, Currency>> {
+        self.edges
+            .get(&base.currency_id())?
+            .get(&quote.currency_id())
+            .map(|edge| &edge.price)
+    }
+
+    /// Returns the cross rate between two currencies through the path with the fewest hops.
+    ///
+    /// Among paths of equal length, the first one in currency order is used.
+    #[inline]
+    pub fn cross_rate(
+        &self,
+        base: &impl BaseCurrency,
+        quote: &impl BaseCurrency,
+    ) -> Option<PricePath> {
+        let start = self.currencies.get(&base.currency_id())?;
+        let target = quote.currency_id();
+        if start.equals(quote) {
+            return Some(Self::identity(start));
+        }
+        // breadth first search recording the predecessor of each visited currency
+        let mut predecessors = BTreeMap::from([(start.currency_id(), start.currency_id())]);
+        let mut frontier = vec![start.currency_id()];
+        while !frontier.is_empty() && !predecessors.contains_key(&target) {
+            let mut next = Vec::new();
+            for id in frontier {
+                for &neighbor in self.edges.get(&id).into_iter().flat_map(BTreeMap::keys) {
+                    if let alloc::collections::btree_map::Entry::Vacant(entry) =
+                        predecessors.entry(neighbor)
+                    {
+                        entry.insert(id);
+                        next.push(neighbor);
+                    }
+                }
+            }
+            frontier = next;
+        }
+        predecessors.get(&target)?;
+        let mut ids = vec![target];
+        while let Some(&previous) = predecessors.get(ids.last().unwrap()) {
+            if previous == *ids.last().unwrap() {
+                break;
+            }
+            ids.push(previous);
+        }
+        ids.reverse();
+        self.path(&ids).ok()
+    }
+
+    /// Returns the highest cross rate between two currencies over all paths of at most `max_hops`
+    /// prices that visit each currency at most once.
+    ///
+    /// The number of paths grows exponentially with `max_hops`, which should be kept small.
+    #[inline]
+    pub fn best_cross_rate(
+        &self,
+        base: &impl BaseCurrency,
+        quote: &impl BaseCurrency,
+        max_hops: usize,
+    ) -> Option<PricePath> {
+        let start = self.currencies.get(&base.currency_id())?;
+        if start.equals(quote) {
+            return Some(Self::identity(start));
+        }
+        let mut best = None;
+        let mut ids = vec![start.currency_id()];
+        self.search_best(&quote.currency_id(), max_hops, &mut ids, &mut best);
+        best
+    }
+
+    /// Depth first search over simple paths extending `ids`, keeping the best path to `target`.
+    #[inline]
+    fn search_best(
+        &self,
+        target: &CurrencyId,
+        max_hops: usize,
+        ids: &mut Vec<CurrencyId>,
+        best: &mut Option<PricePath>,
+    ) {
+        if ids.len() > max_hops {
+            return;
+        }
+        let last = *ids.last().unwrap();
+        for &neighbor in self.edges.get(&last).into_iter().flat_map(BTreeMap::keys) {
+            if ids.contains(&neighbor) {
+                continue;
+            }
+            ids.push(neighbor);
+            if neighbor == *target {
+                if let Ok(candidate) = self.path(ids) {
+                    if best
+                        .as_ref()
+                        .is_none_or(|best| candidate.price.as_fraction() > best.price.as_fraction())
+                    {
+                        *best = Some(candidate);
+                    }
+                }
+            } else {
+                self.search_best(target, max_hops, ids, best);
+            }
+            ids.pop();
+        }
+    }
+
+    /// Chains the prices along a path of at least two currencies.
+    #[inline]
+    fn path(&self, ids: &[CurrencyId]) -> Result<PricePath, Error> {
+        let mut price = self.edges[&ids[0]][&ids[1]].price.clone();
+        for pair in ids[1..].windows(2) {
+            price = price.multiply(&self.edges[&pair[0]][&pair[1]].price)?;
+        }
+        Ok(PricePath {
+            price,
+            path: ids.iter().map(|id| self.currencies[id].clone()).collect(),
+        })
+    }
+
+    #[inline]
+    fn identity(currency: &Currency) -> PricePath {
+        PricePath {
+            price: Price::new(currency.clone(), currency.clone(), 1, 1),
+            path: vec![currency.clone()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref WETH: Currency = WETH9::on_chain(1).unwrap().into();
+        static ref USDC: Currency =
+            token!(1, "A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", 6, "USDC").into();
+        static ref DAI: Currency =
+            token!(1, "6B175474E89094C44Da98b954EedeAC495271d0F", 18, "DAI").into();
+        static ref PEPE: Currency =
+            token!(1, "6982508145454Ce325dDbE47a25d4ec3d2311933", 18, "PEPE").into();
+        static ref LONELY: Currency =
+            token!(1, "0000000000000000000000000000000000000001", 18).into();
+    }
+
+    /// Creates a price from human readable amounts of the base and quote currencies.
+    fn price(
+        base: &Currency,
+        quote: &Currency,
+        base_amount: i64,
+        quote_amount: i64,
+    ) -> Price<Currency, Currency> {
+        Price::new(
+            base.clone(),
+            quote.clone(),
+            BigInt::from(base_amount) * BigInt::from(10).pow(base.decimals() as u32),
+            BigInt::from(quote_amount) * BigInt::from(10).pow(quote.decimals() as u32),
+        )
+    }
+
+    fn graph() -> PriceGraph {
+        let mut graph = PriceGraph::new();
+        // 1 WETH = 2000 USDC
+        graph.add_price(price(&WETH, &USDC, 1, 2000)).unwrap();
+        // 1 USDC = 1 DAI
+        graph.add_price(price(&USDC, &DAI, 1, 1)).unwrap();
+        // 1 WETH = 2100 DAI
+        graph.add_price(price(&WETH, &DAI, 1, 2100)).unwrap();
+        // 1_000_000 PEPE = 1 WETH
+        graph.add_price(price(&PEPE, &WETH, 1_000_000, 1)).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_direct_and_inverted() {
+        let graph = graph();
+        assert_eq!(graph.currencies().count(), 4);
+        assert_eq!(graph.prices().count(), 8);
+        let path = graph.cross_rate(&USDC.clone(), &WETH.clone()).unwrap();
+        assert_eq!(path.path, vec![USDC.clone(), WETH.clone()]);
+        assert_eq!(path.price.to_significant(5, None).unwrap(), "0.0005");
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let graph = graph();
+        let path = graph.cross_rate(&PEPE.clone(), &USDC.clone()).unwrap();
+        assert_eq!(path.path, vec![PEPE.clone(), WETH.clone(), USDC.clone()]);
+        assert_eq!(path.price.to_significant(5, None).unwrap(), "0.002");
+        let amount =
+            CurrencyAmount::from_raw_amount(PEPE.clone(), BigInt::from(10).pow(24)).unwrap();
+        assert_eq!(path.price.quote(&amount).unwrap().to_exact(), "2000");
+    }
+
+    #[test]
+    fn test_best_path() {
+        let graph = graph();
+        let shortest = graph.cross_rate(&PEPE.clone(), &DAI.clone()).unwrap();
+        assert_eq!(shortest.path, vec![PEPE.clone(), WETH.clone(), DAI.clone()]);
+
+        let best = graph
+            .best_cross_rate(&USDC.clone(), &DAI.clone(), 3)
+            .unwrap();
+        // USDC -> WETH -> DAI yields 1.05 DAI per USDC
+        assert_eq!(best.path, vec![USDC.clone(), WETH.clone(), DAI.clone()]);
+        assert_eq!(best.price.to_significant(5, None).unwrap(), "1.05");
+
+        let direct = graph
+            .best_cross_rate(&USDC.clone(), &DAI.clone(), 1)
+            .unwrap();
+        assert_eq!(direct.path, vec![USDC.clone(), DAI.clone()]);
+    }
+
+    #[test]
+    fn test_explicit_reverse_price() {
+        let mut graph = graph();
+        // 1 DAI = 0.99 USDC in the other direction
+        graph.add_price(price(&DAI, &USDC, 100, 99)).unwrap();
+        assert_eq!(
+            graph
+                .price(&DAI.clone(), &USDC.clone())
+                .unwrap()
+                .to_significant(5, None)
+                .unwrap(),
+            "0.99"
+        );
+        // re-adding the forward price does not override the explicit reverse price
+        graph.add_price(price(&USDC, &DAI, 1, 1)).unwrap();
+        assert_eq!(
+            graph
+                .price(&DAI.clone(), &USDC.clone())
+                .unwrap()
+                .to_significant(5, None)
+                .unwrap(),
+            "0.99"
+        );
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let mut graph = graph();
+        assert_eq!(graph.cross_rate(&USDC.clone(), &LONELY.clone()), None);
+        assert_eq!(
+            graph.best_cross_rate(&LONELY.clone(), &USDC.clone(), 4),
+            None
+        );
+        graph.add_price(price(&LONELY, &PEPE, 1, 1)).unwrap();
+        assert_eq!(
+            graph.best_cross_rate(&LONELY.clone(), &USDC.clone(), 2),
+            None
+        );
+        assert_eq!(
+            graph
+                .best_cross_rate(&LONELY.clone(), &USDC.clone(), 3)
+                .unwrap()
+                .path
+                .len(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_identity() {
+        let path = graph().cross_rate(&USDC.clone(), &USDC.clone()).unwrap();
+        assert_eq!(path.path, vec![USDC.clone()]);
+        assert_eq!(path.price.to_significant(1, None).unwrap(), "1");
+    }
+
+    #[test]
+    fn test_invalid_price() {
+        let mut graph = PriceGraph::new();
+        assert_eq!(
+            graph.add_price(Price::new(USDC.clone(), DAI.clone(), 1, 0)),
+            Err(Error::ZeroQuote)
+        );
+        assert_eq!(
+            graph.add_price(Price::new(USDC.clone(), DAI.clone(), 1, -1)),
+            Err(Error::Negative)
+        );
+        assert_eq!(
+            graph.add_price(Price::new(USDC.clone(), USDC.clone(), 1, 1)),
+            Err(Error::EqualAddresses)
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use crate::prelude::*;
+use alloy_primitives::ChainId;
+
+/// An ordered list of pools through which a swap from the input to the output currency occurs.
+#[derive(Clone, Debug)]
+pub struct Route<TInput: BaseCurrency, TOutput: BaseCurrency, P: SwapPool> {
+    /// The pools swapped through, in order
+    pub pools: Vec<P>,
+    /// The currencies traversed, starting with the input and ending with the output currency
+    pub path: Vec<Currency>,
+    /// The input currency
+    pub input: TInput,
+    /// The output currency
+    pub output: TOutput,
+}
+
+impl<TInput, TOutput, P> Route<TInput, TOutput, P>
+where
+    TInput: BaseCurrency,
+    TOutput: BaseCurrency,
+    P: SwapPool,
+{
+    /// Creates a route between the input and output currency through the pools.
+    ///
+    /// # Arguments
+    ///
+    /// * `pools`: the pools, where each shares a currency with the next one
+    /// * `input`: the input currency, which must be in the first pool
+    /// * `output`: the output currency, which must be in the last pool
+    #[inline]
+    pub fn new(pools: Vec<P>, input: TInput, output: TOutput) -> Result<Self, Error> {
+        let first = pools.first().ok_or(Error::Empty)?;
+        let chain_id = first.currency0().chain_id();
+        if let Some(other) = pools
+            .iter()
+            .flat_map(|pool| [pool.currency0().chain_id(), pool.currency1().chain_id()])
+            .find(|other| *other != chain_id)
+        {
+            return Err(Error::ChainIdMismatch(chain_id, other));
+        }
+        if !first.involves_currency(&input) {
+            return Err(Error::InvalidPath);
+        }
+        if !pools.last().unwrap().involves_currency(&output) {
+            return Err(Error::InvalidPath);
+        }
+
+        let mut path = Vec::with_capacity(pools.len() + 1);
+        let mut current = if first.currency0().equals(&input) {
+            first.currency0()
+        } else {
+            first.currency1()
+        };
+        path.push(current.clone());
+        for pool in &pools {
+            current = if pool.currency0().equals(current) {
+                pool.currency1()
+            } else if pool.currency1().equals(current) {
+                pool.currency0()
+            } else {
+                return Err(Error::InvalidPath);
+            };
+            path.push(current.clone());
+        }
+        if !current.equals(&output) {
+            return Err(Error::InvalidPath);
+        }
+
+        Ok(Self {
+            pools,
+            path,
+            input,
+            output,
+        })
+    }
+
+    /// Returns the chain ID of the route.
+    #[inline]
+    pub fn chain_id(&self) -> ChainId {
+        self.path[0].chain_id()
+    }
+
+    /// Returns the mid price of the input currency in terms of the output currency, i.e. the
+    /// product of the mid prices of the pools along the path.
+    #[inline]
+    pub fn mid_price(&self) -> Result<Price<TInput, TOutput>, Error> {
+        let mut price = self.pools[0].price_of(&self.path[0])?;
+        for (pool, currency) in self.pools.iter().zip(&self.path).skip(1) {
+            price = price.multiply(&pool.price_of(currency)?)?;
+        }
+        Ok(Price::new(
+            self.input.clone(),
+            self.output.clone(),
+            price.denominator,
+            price.numerator,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{entities::swap_pool::tests::ConstantProductPool, token};
+    use alloc::vec;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref TOKEN0: Token = token!(1, "0000000000000000000000000000000000000001", 18, "t0");
+        static ref TOKEN1: Token = token!(1, "0000000000000000000000000000000000000002", 18, "t1");
+        static ref TOKEN2: Token = token!(1, "0000000000000000000000000000000000000003", 18, "t2");
+        static ref POOL_0_1: ConstantProductPool =
+            ConstantProductPool::new(TOKEN0.clone(), 100, TOKEN1.clone(), 200);
+        static ref POOL_1_2: ConstantProductPool =
+            ConstantProductPool::new(TOKEN1.clone(), 150, TOKEN2.clone(), 100);
+    }
+
+    #[test]
+    fn test_path() {
+        let route = Route::new(
+            vec![POOL_0_1.clone(), POOL_1_2.clone()],
+            TOKEN0.clone(),
+            TOKEN2.clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            route.path,
+            vec![
+                Currency::from(TOKEN0.clone()),
+                TOKEN1.clone().into(),
+                TOKEN2.clone().into()
+            ]
+        );
+        assert_eq!(route.chain_id(), 1);
+
+        let reversed = Route::new(
+            vec![POOL_1_2.clone(), POOL_0_1.clone()],
+            TOKEN2.clone(),
+            TOKEN0.clone(),
+        )
+        .unwrap();
+        assert_eq!(reversed.path[1], Currency::from(TOKEN1.clone()));
+    }
+
+    #[test]
+    fn test_mid_price() {
+        let route = Route::new(
+            vec![POOL_0_1.clone(), POOL_1_2.clone()],
+            TOKEN0.clone(),
+            TOKEN2.clone(),
+        )
+        .unwrap();
+        // 200 / 100 * 100 / 150
+        let price = route.mid_price().unwrap();
+        assert_eq!(price.to_significant(5, None).unwrap(), "1.3333");
+        assert_eq!(price.base_currency, TOKEN0.clone());
+        assert_eq!(price.quote_currency, TOKEN2.clone());
+        assert_eq!(
+            route
+                .mid_price()
+                .unwrap()
+                .invert()
+                .to_significant(5, None)
+                .unwrap(),
+            "0.75"
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let pools: Vec<ConstantProductPool> = vec![];
+        assert_eq!(
+            Route::new(pools, TOKEN0.clone(), TOKEN1.clone()).unwrap_err(),
+            Error::Empty
+        );
+        assert_eq!(
+            Route::new(vec![POOL_1_2.clone()], TOKEN0.clone(), TOKEN2.clone()).unwrap_err(),
+            Error::InvalidPath
+        );
+        assert_eq!(
+            Route::new(vec![POOL_0_1.clone()], TOKEN0.clone(), TOKEN2.clone()).unwrap_err(),
+            Error::InvalidPath
+        );
+        assert_eq!(
+            Route::new(
+                vec![POOL_0_1.clone(), POOL_0_1.clone()],
+                TOKEN0.clone(),
+                TOKEN1.clone()
+            )
+            .unwrap_err(),
+            Error::InvalidPath
+        );
+        let other_chain = ConstantProductPool::new(
+            token!(2, "0000000000000000000000000000000000000002", 18),
+            100,
+            TOKEN2.clone(),
+            100,
+        );
+        assert_eq!(
+            Route::new(
+                vec![POOL_0_1.clone(), other_chain],
+                TOKEN0.clone(),
+                TOKEN2.clone()
+            )
+            .unwrap_err(),
+            Error::ChainIdMismatch(1, 2)
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use crate::prelude::*;
+
+/// A pool between two currencies that a [`Route`] can swap through.
+///
+/// Implement this for any pool model, Uniswap or not, to reuse the [`Route`] and [`Trade`]
+/// machinery with it.
+pub trait SwapPool {
+    /// The first currency of the pool
+    fn currency0(&self) -> &Currency;
+
+    /// The second currency of the pool
+    fn currency1(&self) -> &Currency;
+
+    /// Returns whether the pool involves the currency.
+    #[inline]
+    fn involves_currency(&self, currency: &impl BaseCurrency) -> bool {
+        self.currency0().equals(currency) || self.currency1().equals(currency)
+    }
+
+    /// Returns the current mid price of the currency in terms of the other currency of the pool.
+    fn price_of(&self, currency: &impl BaseCurrency) -> Result<Price<Currency, Currency>, Error>;
+
+    /// Returns the amount of the other currency received for swapping the input amount.
+    fn get_output_amount(
+        &self,
+        input_amount: &CurrencyAmount<Currency>,
+    ) -> Result<CurrencyAmount<Currency>, Error>;
+
+    /// Returns the amount of the other currency required to receive the output amount.
+    fn get_input_amount(
+        &self,
+        output_amount: &CurrencyAmount<Currency>,
+    ) -> Result<CurrencyAmount<Currency>, Error>;
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A constant product pool with a 0.3% fee, as in Uniswap v2.
+    #[derive(Clone, Debug)]
+    pub(crate) struct ConstantProductPool {
+        reserve0: CurrencyAmount<Currency>,
+        reserve1: CurrencyAmount<Currency>,
+    }
+
+    impl ConstantProductPool {
+        pub(crate) fn new(
+            currency0: impl Into<Currency>,
+            reserve0: i64,
+            currency1: impl Into<Currency>,
+            reserve1: i64,
+        ) -> Self {
+            Self {
+                reserve0: CurrencyAmount::from_raw_amount(currency0.into(), reserve0).unwrap(),
+                reserve1: CurrencyAmount::from_raw_amount(currency1.into(), reserve1).unwrap(),
+            }
+        }
+
+        fn reserves(
+            &self,
+            currency: &impl BaseCurrency,
+        ) -> Result<(&CurrencyAmount<Currency>, &CurrencyAmount<Currency>), Error> {
+            if self.reserve0.currency.equals(currency) {
+                Ok((&self.reserve0, &self.reserve1))
+            } else if self.reserve1.currency.equals(currency) {
+                Ok((&self.reserve1, &self.reserve0))
+            } else {
+                Err(Error::CurrencyMismatch)
+            }
+        }
+    }
+
+    impl SwapPool for ConstantProductPool {
+        fn currency0(&self) -> &Currency {
+            &self.reserve0.currency
+        }
+
+        fn currency1(&self) -> &Currency {
+            &self.reserve1.currency
+        }
+
+        fn price_of(
+            &self,
+            currency: &impl BaseCurrency,
+        ) -> Result<Price<Currency, Currency>, Error> {
+            let (base, quote) = self.reserves(currency)?;
+            Ok(Price::new(
+                base.currency.clone(),
+                quote.currency.clone(),
+                base.quotient(),
+                quote.quotient(),
+            ))
+        }
+
+        fn get_output_amount(
+            &self,
+            input_amount: &CurrencyAmount<Currency>,
+        ) -> Result<CurrencyAmount<Currency>, Error> {
+            let (reserve_in, reserve_out) = self.reserves(&input_amount.currency)?;
+            let input = input_amount.quotient() * BigInt::from(997);
+            let output = input * reserve_out.quotient()
+                / (reserve_in.quotient() * BigInt::from(1000) + input);
+            CurrencyAmount::from_raw_amount(reserve_out.currency.clone(), output)
+        }
+
+        fn get_input_amount(
+            &self,
+            output_amount: &CurrencyAmount<Currency>,
+        ) -> Result<CurrencyAmount<Currency>, Error> {
+            let (reserve_out, reserve_in) = self.reserves(&output_amount.currency)?;
+            let output = output_amount.quotient();
+            if output >= reserve_out.quotient() {
+                return Err(Error::InsufficientBalance {
+                    available: U256::from_big_int(reserve_out.quotient()),
+                    required: U256::from_big_int(output),
+                });
+            }
+            let input = reserve_in.quotient() * output * BigInt::from(1000)
+                / ((reserve_out.quotient() - output) * BigInt::from(997))
+                + BigInt::ONE;
+            CurrencyAmount::from_raw_amount(reserve_in.currency.clone(), input)
+        }
+    }
+
+    #[test]
+    fn test_involves_currency() {
+        let token0 = crate::token!(1, "0000000000000000000000000000000000000001", 18);
+        let token1 = crate::token!(1, "0000000000000000000000000000000000000002", 18);
+        let pool = ConstantProductPool::new(token0.clone(), 100, Ether::on_chain(1), 100);
+        assert!(pool.involves_currency(&token0));
+        assert!(pool.involves_currency(&Ether::on_chain(1)));
+        assert!(!pool.involves_currency(&token1));
+        assert!(!pool.involves_currency(&Ether::on_chain(2)));
+    }
+}
@@ -35,6 +35,15 @@ macro_rules! impl_base_currency {
 impl_base_currency!(Token, &Token);
 
 impl Token {
+    /// The maximum number of decimals accepted by [`TokenBuilder`].
+    ///
+    /// Raw amounts are capped at [`MAX_UINT256`], which has 78 digits, so a token with more
+    /// decimals could not represent a single whole unit.
+    pub const MAX_DECIMALS: u8 = 77;
+
+    /// The maximum buy or sell fee in basis points, i.e. 100%.
+    pub const MAX_FEE_BPS: u64 = 10_000;
+
     /// Creates a new [`Token`] with the given parameters.
     ///
     /// # Arguments
@@ -96,6 +105,158 @@ impl Token {
         }
         Ok(self.address() < other.address())
     }
+
+    /// Returns a [`TokenBuilder`] for a token on the given chain.
+    ///
+    /// Unlike [`Token::new`] and the [`token!`](crate::token) macro, which are meant for trusted
+    /// literals, the builder validates its inputs.
+    #[inline]
+    #[must_use]
+    pub const fn builder(chain_id: u64) -> TokenBuilder {
+        TokenBuilder {
+            chain_id,
+            address: Ok(Address::ZERO),
+            decimals: 18,
+            symbol: None,
+            name: None,
+            buy_fee_bps: 0,
+            sell_fee_bps: 0,
+        }
+    }
+}
+
+/// A fallible builder for [`Token`] that validates the chain ID, address, decimals and fees.
+///
+/// # Example
+///
+/// ```
+/// use uniswap_sdk_core::prelude::*;
+///
+/// let dai = Token::builder(1)
+///     .address_str("0x6B175474E89094C44Da98b954EedeAC495271d0F")
+///     .decimals(18)
+///     .symbol("DAI")
+///     .name("Dai Stablecoin")
+///     .build()
+///     .unwrap();
+/// assert_eq!(dai.symbol(), Some(&"DAI".to_string()));
+///
+/// let err = Token::builder(1)
+///     .address_str("0x6b175474E89094C44Da98b954EedeAC495271d0F")
+///     .build()
+///     .unwrap_err();
+/// assert!(matches!(err, Error::ChecksumMismatch(_)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenBuilder {
+    chain_id: u64,
+    address: Result<Address, Error>,
+    decimals: u8,
+    symbol: Option<String>,
+    name: Option<String>,
+    buy_fee_bps: u64,
+    sell_fee_bps: u64,
+}
+
+impl TokenBuilder {
+    /// Sets the address of the token.
+    #[inline]
+    #[must_use]
+    pub const fn address(mut self, address: Address) -> Self {
+        self.address = Ok(address);
+        self
+    }
+
+    /// Parses and sets the address of the token, with or without the `0x` prefix.
+    ///
+    /// Mixed-case addresses must match their EIP-55 checksum, while all lowercase or all uppercase
+    /// addresses are accepted as is.
+    #[inline]
+    #[must_use]
+    pub fn address_str(mut self, address: &str) -> Self {
+        self.address = parse_checksummed_address(address);
+        self
+    }
+
+    /// Sets the decimals of the token, 18 by default.
+    #[inline]
+    #[must_use]
+    pub const fn decimals(mut self, decimals: u8) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Sets the symbol of the token.
+    #[inline]
+    #[must_use]
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_string());
+        self
+    }
+
+    /// Sets the name of the token.
+    #[inline]
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the buy fee in basis points.
+    #[inline]
+    #[must_use]
+    pub const fn buy_fee_bps(mut self, buy_fee_bps: u64) -> Self {
+        self.buy_fee_bps = buy_fee_bps;
+        self
+    }
+
+    /// Sets the sell fee in basis points.
+    #[inline]
+    #[must_use]
+    pub const fn sell_fee_bps(mut self, sell_fee_bps: u64) -> Self {
+        self.sell_fee_bps = sell_fee_bps;
+        self
+    }
+
+    /// Validates the inputs and builds the [`Token`].
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ZeroChainId`] if the chain ID is zero
+    /// * [`Error::InvalidAddress`] or [`Error::ChecksumMismatch`] if the address string is invalid
+    /// * [`Error::ZeroAddress`] if the address is the zero address or was never set
+    /// * [`Error::DecimalsOutOfRange`] if the decimals exceed [`Token::MAX_DECIMALS`]
+    /// * [`Error::FeeBpsOutOfRange`] if a fee exceeds [`Token::MAX_FEE_BPS`]
+    #[inline]
+    pub fn build(self) -> Result<Token, Error> {
+        if self.chain_id == 0 {
+            return Err(Error::ZeroChainId);
+        }
+        let address = self.address?;
+        if address.is_zero() {
+            return Err(Error::ZeroAddress);
+        }
+        if self.decimals > Token::MAX_DECIMALS {
+            return Err(Error::DecimalsOutOfRange {
+                decimals: self.decimals,
+                max: Token::MAX_DECIMALS,
+            });
+        }
+        for fee in [self.buy_fee_bps, self.sell_fee_bps] {
+            if fee > Token::MAX_FEE_BPS {
+                return Err(Error::FeeBpsOutOfRange(fee));
+            }
+        }
+        Ok(Token::new(
+            self.chain_id,
+            address,
+            self.decimals,
+            self.symbol,
+            self.name,
+            self.buy_fee_bps,
+            self.sell_fee_bps,
+        ))
+    }
 }
 
 /// Shorthand macro to create a [`Token`] with the given chain id, address, decimals, optional
@@ -291,6 +452,93 @@ mod tests {
         assert!(token.equals(&token_1));
     }
 
+    #[test]
+    fn test_builder() {
+        let token = Token::builder(1)
+            .address_str(DAI_MAINNET)
+            .decimals(18)
+            .symbol("DAI")
+            .name("Dai Stablecoin")
+            .buy_fee_bps(100)
+            .sell_fee_bps(200)
+            .build()
+            .unwrap();
+        assert_eq!(
+            token,
+            Token::new(
+                1,
+                DAI_MAINNET.parse().unwrap(),
+                18,
+                Some("DAI".to_string()),
+                Some("Dai Stablecoin".to_string()),
+                100,
+                200
+            )
+        );
+    }
+
+    #[test]
+    fn test_builder_address_formats() {
+        let expected = Token::builder(1).address_str(DAI_MAINNET).build().unwrap();
+        for address in [
+            "6B175474E89094C44Da98b954EedeAC495271d0F",
+            "0x6b175474e89094c44da98b954eedeac495271d0f",
+            "0x6B175474E89094C44DA98B954EEDEAC495271D0F",
+        ] {
+            assert_eq!(
+                Token::builder(1).address_str(address).build().unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_builder_errors() {
+        assert_eq!(
+            Token::builder(0).address_str(DAI_MAINNET).build(),
+            Err(Error::ZeroChainId)
+        );
+        assert_eq!(Token::builder(1).build(), Err(Error::ZeroAddress));
+        assert_eq!(
+            Token::builder(1)
+                .address_str("0x0000000000000000000000000000000000000000")
+                .build(),
+            Err(Error::ZeroAddress)
+        );
+        assert_eq!(
+            Token::builder(1).address_str("0x1234").build(),
+            Err(Error::InvalidAddress)
+        );
+        assert_eq!(
+            Token::builder(1)
+                .address_str("0x6b175474E89094C44Da98b954EedeAC495271d0F")
+                .build(),
+            Err(Error::ChecksumMismatch(DAI_MAINNET.parse().unwrap()))
+        );
+        assert_eq!(
+            Token::builder(1)
+                .address_str(DAI_MAINNET)
+                .decimals(78)
+                .build(),
+            Err(Error::DecimalsOutOfRange {
+                decimals: 78,
+                max: 77
+            })
+        );
+        assert!(Token::builder(1)
+            .address_str(DAI_MAINNET)
+            .decimals(77)
+            .build()
+            .is_ok());
+        assert_eq!(
+            Token::builder(1)
+                .address_str(DAI_MAINNET)
+                .sell_fee_bps(10_001)
+                .build(),
+            Err(Error::FeeBpsOutOfRange(10_001))
+        );
+    }
+
     #[test]
     fn test_true_if_one_token_is_checksummed_and_the_other_is_not() {
         let token_a = token!(1, DAI_MAINNET, 18, "DAI");
@@ -0,0 +1,458 @@
+use crate::prelude::*;
+use alloc::vec;
+use core::cmp::Ordering;
+
+/// A swap of an amount along a single [`Route`], one of the splits of a [`Trade`].
+#[derive(Clone, Debug)]
+pub struct Swap<TInput: BaseCurrency, TOutput: BaseCurrency, P: SwapPool> {
+    /// The route of the swap
+    pub route: Route<TInput, TOutput, P>,
+    /// The amount swapped into the route
+    pub input_amount: CurrencyAmount<TInput>,
+    /// The amount received from the route
+    pub output_amount: CurrencyAmount<TOutput>,
+}
+
+impl<TInput, TOutput, P> Swap<TInput, TOutput, P>
+where
+    TInput: BaseCurrency,
+    TOutput: BaseCurrency,
+    P: SwapPool,
+{
+    /// Creates a swap along a route with known input and output amounts.
+    #[inline]
+    pub fn new(
+        route: Route<TInput, TOutput, P>,
+        input_amount: CurrencyAmount<TInput>,
+        output_amount: CurrencyAmount<TOutput>,
+    ) -> Result<Self, Error> {
+        if !input_amount.currency.equals(&route.input)
+            || !output_amount.currency.equals(&route.output)
+        {
+            return Err(Error::CurrencyMismatch);
+        }
+        Ok(Self {
+            route,
+            input_amount,
+            output_amount,
+        })
+    }
+
+    /// Simulates a swap along a route by computing the amount of the other side through its pools.
+    ///
+    /// # Arguments
+    ///
+    /// * `route`: the route to swap along
+    /// * `amount`: the input amount for [`TradeType::ExactInput`], or the output amount for
+    ///   [`TradeType::ExactOutput`]
+    /// * `trade_type`: whether the amount is the input or the output of the swap
+    #[inline]
+    pub fn from_route<TAmount: BaseCurrency>(
+        route: Route<TInput, TOutput, P>,
+        amount: &CurrencyAmount<TAmount>,
+        trade_type: TradeType,
+    ) -> Result<Self, Error> {
+        match trade_type {
+            TradeType::ExactInput => {
+                if !amount.currency.equals(&route.input) {
+                    return Err(Error::CurrencyMismatch);
+                }
+                let mut current = CurrencyAmount::from_fractional_amount(
+                    route.path[0].clone(),
+                    amount.numerator,
+                    amount.denominator,
+                )?;
+                for pool in &route.pools {
+                    current = pool.get_output_amount(&current)?;
+                }
+                Ok(Self {
+                    input_amount: CurrencyAmount::from_fractional_amount(
+                        route.input.clone(),
+                        amount.numerator,
+                        amount.denominator,
+                    )?,
+                    output_amount: CurrencyAmount::from_fractional_amount(
+                        route.output.clone(),
+                        current.numerator,
+                        current.denominator,
+                    )?,
+                    route,
+                })
+            }
+            TradeType::ExactOutput => {
+                if !amount.currency.equals(&route.output) {
+                    return Err(Error::CurrencyMismatch);
+                }
+                let mut current = CurrencyAmount::from_fractional_amount(
+                    route.path.last().unwrap().clone(),
+                    amount.numerator,
+                    amount.denominator,
+                )?;
+                for pool in route.pools.iter().rev() {
+                    current = pool.get_input_amount(&current)?;
+                }
+                Ok(Self {
+                    input_amount: CurrencyAmount::from_fractional_amount(
+                        route.input.clone(),
+                        current.numerator,
+                        current.denominator,
+                    )?,
+                    output_amount: CurrencyAmount::from_fractional_amount(
+                        route.output.clone(),
+                        amount.numerator,
+                        amount.denominator,
+                    )?,
+                    route,
+                })
+            }
+        }
+    }
+}
+
+/// A trade of an input amount for an output amount, split across one or more routes.
+#[derive(Clone, Debug)]
+pub struct Trade<TInput: BaseCurrency, TOutput: BaseCurrency, P: SwapPool> {
+    /// The swaps of the trade, one per route
+    pub swaps: Vec<Swap<TInput, TOutput, P>>,
+    /// Whether the input or the output amount of the trade is fixed
+    pub trade_type: TradeType,
+    input_amount: CurrencyAmount<TInput>,
+    output_amount: CurrencyAmount<TOutput>,
+}
+
+impl<TInput, TOutput, P> Trade<TInput, TOutput, P>
+where
+    TInput: BaseCurrency,
+    TOutput: BaseCurrency,
+    P: SwapPool,
+{
+    /// Creates a trade from its swaps, which must share the same input and output currencies.
+    #[inline]
+    pub fn new(swaps: Vec<Swap<TInput, TOutput, P>>, trade_type: TradeType) -> Result<Self, Error> {
+        if swaps.is_empty() {
+            return Err(Error::Empty);
+        }
+        Ok(Self {
+            input_amount: CurrencyAmount::try_sum(swaps.iter().map(|swap| &swap.input_amount))?,
+            output_amount: CurrencyAmount::try_sum(swaps.iter().map(|swap| &swap.output_amount))?,
+            swaps,
+            trade_type,
+        })
+    }
+
+    /// Creates a trade along a single route, simulating the swap through its pools.
+    #[inline]
+    pub fn from_route<TAmount: BaseCurrency>(
+        route: Route<TInput, TOutput, P>,
+        amount: &CurrencyAmount<TAmount>,
+        trade_type: TradeType,
+    ) -> Result<Self, Error> {
+        Self::new(
+            vec![Swap::from_route(route, amount, trade_type)?],
+            trade_type,
+        )
+    }
+
+    /// Creates a trade split across multiple routes, simulating each swap through its pools.
+    #[inline]
+    pub fn from_routes<TAmount: BaseCurrency>(
+        routes: Vec<(Route<TInput, TOutput, P>, CurrencyAmount<TAmount>)>,
+        trade_type: TradeType,
+    ) -> Result<Self, Error> {
+        let swaps = routes
+            .into_iter()
+            .map(|(route, amount)| Swap::from_route(route, &amount, trade_type))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(swaps, trade_type)
+    }
+
+    /// The total input amount of the trade
+    #[inline]
+    #[must_use]
+    pub const fn input_amount(&self) -> &CurrencyAmount<TInput> {
+        &self.input_amount
+    }
+
+    /// The total output amount of the trade
+    #[inline]
+    #[must_use]
+    pub const fn output_amount(&self) -> &CurrencyAmount<TOutput> {
+        &self.output_amount
+    }
+
+    /// The price expressed in terms of output amount/input amount.
+    #[inline]
+    pub fn execution_price(&self) -> Price<TInput, TOutput> {
+        Price::from_currency_amounts(self.input_amount.clone(), self.output_amount.clone())
+    }
+
+    /// Returns the percent difference between the mid price of the routes and the execution price.
+    ///
+    /// The mid price of a split trade is the one at which every swap would receive the output
+    /// quoted by the mid price of its route.
+    #[inline]
+    pub fn price_impact(&self) -> Result<Percent, Error> {
+        if self.input_amount.numerator == BigInt::ZERO {
+            return Err(Error::ZeroQuote);
+        }
+        let spot_output_amount = CurrencyAmount::try_sum(
+            self.swaps
+                .iter()
+                .map(|swap| swap.route.mid_price()?.quote(&swap.input_amount))
+                .collect::<Result<Vec<_>, _>>()?,
+        )?;
+        let mid_price = Price::from_currency_amounts(self.input_amount.clone(), spot_output_amount);
+        compute_price_impact(&mid_price, &self.input_amount, &self.output_amount)
+    }
+
+    /// Returns the minimum amount that must be received for the trade to stay within the slippage
+    /// tolerance.
+    #[inline]
+    pub fn minimum_amount_out(
+        &self,
+        slippage_tolerance: &SlippageTolerance,
+    ) -> Result<CurrencyAmount<TOutput>, Error> {
+        slippage_tolerance.minimum_amount_out(self.trade_type, &self.output_amount)
+    }
+
+    /// Returns the maximum amount that may be spent for the trade to stay within the slippage
+    /// tolerance.
+    #[inline]
+    pub fn maximum_amount_in(
+        &self,
+        slippage_tolerance: &SlippageTolerance,
+    ) -> Result<CurrencyAmount<TInput>, Error> {
+        slippage_tolerance.maximum_amount_in(self.trade_type, &self.input_amount)
+    }
+
+    /// Returns the worst price the trade may execute at within the slippage tolerance.
+    #[inline]
+    pub fn worst_execution_price(
+        &self,
+        slippage_tolerance: &SlippageTolerance,
+    ) -> Result<Price<TInput, TOutput>, Error> {
+        slippage_tolerance.worst_execution_price(
+            self.trade_type,
+            &self.input_amount,
+            &self.output_amount,
+        )
+    }
+
+    /// Returns the total number of pools swapped through.
+    #[inline]
+    pub fn hops(&self) -> usize {
+        self.swaps.iter().map(|swap| swap.route.pools.len()).sum()
+    }
+}
+
+/// Orders trades from best to worst: higher output first, then lower input, then fewer hops.
+///
+/// Meant to keep a list of the best trades with [`sorted_insert`]. Both trades must have the same
+/// input and output currencies.
+#[inline]
+pub fn trade_comparator<TInput, TOutput, P>(
+    a: &Trade<TInput, TOutput, P>,
+    b: &Trade<TInput, TOutput, P>,
+) -> Ordering
+where
+    TInput: BaseCurrency,
+    TOutput: BaseCurrency,
+    P: SwapPool,
+{
+    assert!(
+        a.input_amount.currency.equals(&b.input_amount.currency),
+        "INPUT_CURRENCY"
+    );
+    assert!(
+        a.output_amount.currency.equals(&b.output_amount.currency),
+        "OUTPUT_CURRENCY"
+    );
+    b.output_amount
+        .as_fraction()
+        .cmp(&a.output_amount.as_fraction())
+        .then_with(|| {
+            a.input_amount
+                .as_fraction()
+                .cmp(&b.input_amount.as_fraction())
+        })
+        .then_with(|| a.hops().cmp(&b.hops()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{entities::swap_pool::tests::ConstantProductPool, token};
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref TOKEN0: Token = token!(1, "0000000000000000000000000000000000000001", 18, "t0");
+        static ref TOKEN1: Token = token!(1, "0000000000000000000000000000000000000002", 18, "t1");
+        static ref TOKEN2: Token = token!(1, "0000000000000000000000000000000000000003", 18, "t2");
+        static ref POOL_0_1: ConstantProductPool =
+            ConstantProductPool::new(TOKEN0.clone(), 100_000, TOKEN1.clone(), 100_000);
+        static ref POOL_1_2: ConstantProductPool =
+            ConstantProductPool::new(TOKEN1.clone(), 100_000, TOKEN2.clone(), 100_000);
+        static ref POOL_0_2: ConstantProductPool =
+            ConstantProductPool::new(TOKEN0.clone(), 100_000, TOKEN2.clone(), 110_000);
+    }
+
+    fn direct() -> Route<Token, Token, ConstantProductPool> {
+        Route::new(vec![POOL_0_2.clone()], TOKEN0.clone(), TOKEN2.clone()).unwrap()
+    }
+
+    fn two_hops() -> Route<Token, Token, ConstantProductPool> {
+        Route::new(
+            vec![POOL_0_1.clone(), POOL_1_2.clone()],
+            TOKEN0.clone(),
+            TOKEN2.clone(),
+        )
+        .unwrap()
+    }
+
+    fn amount(token: &Token, raw: i64) -> CurrencyAmount<Token> {
+        CurrencyAmount::from_raw_amount(token.clone(), raw).unwrap()
+    }
+
+    #[test]
+    fn test_exact_input() {
+        let trade =
+            Trade::from_route(direct(), &amount(&TOKEN0, 1000), TradeType::ExactInput).unwrap();
+        // 997_000 * 110_000 / (100_000_000 + 997_000) = 1085.87
+        assert_eq!(trade.output_amount().quotient(), BigInt::from(1085));
+        assert_eq!(trade.input_amount().quotient(), BigInt::from(1000));
+        assert_eq!(
+            trade.execution_price(),
+            Price::new(TOKEN0.clone(), TOKEN2.clone(), 1000, 1085)
+        );
+    }
+
+    #[test]
+    fn test_exact_output() {
+        let trade =
+            Trade::from_route(two_hops(), &amount(&TOKEN2, 1000), TradeType::ExactOutput).unwrap();
+        assert_eq!(trade.output_amount().quotient(), BigInt::from(1000));
+        // 100_000 * 1000 * 1000 / (99_000 * 997) + 1 = 1014, then 1028 for the first pool
+        assert_eq!(trade.input_amount().quotient(), BigInt::from(1028));
+        assert_eq!(trade.hops(), 2);
+        assert_eq!(
+            Trade::from_route(two_hops(), &amount(&TOKEN0, 1000), TradeType::ExactOutput)
+                .unwrap_err(),
+            Error::CurrencyMismatch
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        let trade = Trade::from_routes(
+            vec![
+                (direct(), amount(&TOKEN0, 700)),
+                (two_hops(), amount(&TOKEN0, 300)),
+            ],
+            TradeType::ExactInput,
+        )
+        .unwrap();
+        assert_eq!(trade.swaps.len(), 2);
+        assert_eq!(trade.input_amount().quotient(), BigInt::from(1000));
+        assert_eq!(
+            trade.output_amount().quotient(),
+            trade.swaps[0].output_amount.quotient() + trade.swaps[1].output_amount.quotient()
+        );
+        assert_eq!(trade.hops(), 3);
+        assert_eq!(
+            Trade::<Token, Token, ConstantProductPool>::new(vec![], TradeType::ExactInput)
+                .unwrap_err(),
+            Error::Empty
+        );
+    }
+
+    #[test]
+    fn test_price_impact() {
+        let trade =
+            Trade::from_route(direct(), &amount(&TOKEN0, 1000), TradeType::ExactInput).unwrap();
+        // mid price quotes 1100, the trade receives 1085
+        assert_eq!(
+            trade.price_impact().unwrap(),
+            Percent::new(1100 - 1085, 1100)
+        );
+
+        let split = Trade::from_routes(
+            vec![
+                (direct(), amount(&TOKEN0, 500)),
+                (two_hops(), amount(&TOKEN0, 500)),
+            ],
+            TradeType::ExactInput,
+        )
+        .unwrap();
+        // mid prices quote 550 and 500
+        let spot = BigInt::from(1050);
+        assert_eq!(
+            split.price_impact().unwrap(),
+            Percent::new(spot - split.output_amount().quotient(), spot)
+        );
+    }
+
+    #[test]
+    fn test_slippage() {
+        let slippage = SlippageTolerance::new(Percent::new(1, 100)).unwrap();
+        let trade =
+            Trade::from_route(direct(), &amount(&TOKEN0, 1000), TradeType::ExactInput).unwrap();
+        // 1085 / 1.01 = 1074.26
+        assert_eq!(
+            trade.minimum_amount_out(&slippage).unwrap().quotient(),
+            BigInt::from(1074)
+        );
+        assert_eq!(
+            trade.maximum_amount_in(&slippage).unwrap(),
+            *trade.input_amount()
+        );
+        assert_eq!(
+            trade.worst_execution_price(&slippage).unwrap(),
+            Price::new(TOKEN0.clone(), TOKEN2.clone(), 1000, 1074)
+        );
+    }
+
+    #[test]
+    fn test_comparator() {
+        let best =
+            Trade::from_route(direct(), &amount(&TOKEN0, 1000), TradeType::ExactInput).unwrap();
+        let worse =
+            Trade::from_route(two_hops(), &amount(&TOKEN0, 1000), TradeType::ExactInput).unwrap();
+        let same_output = Trade::new(
+            vec![Swap::new(
+                two_hops(),
+                amount(&TOKEN0, 1000),
+                best.output_amount().clone(),
+            )
+            .unwrap()],
+            TradeType::ExactInput,
+        )
+        .unwrap();
+        let cheaper = Trade::new(
+            vec![Swap::new(
+                two_hops(),
+                amount(&TOKEN0, 999),
+                best.output_amount().clone(),
+            )
+            .unwrap()],
+            TradeType::ExactInput,
+        )
+        .unwrap();
+
+        assert_eq!(trade_comparator(&best, &worse), Ordering::Less);
+        assert_eq!(trade_comparator(&worse, &best), Ordering::Greater);
+        // fewer hops wins ties
+        assert_eq!(trade_comparator(&best, &same_output), Ordering::Less);
+        assert_eq!(trade_comparator(&best, &best), Ordering::Equal);
+        // lower input wins for equal output
+        assert_eq!(trade_comparator(&cheaper, &best), Ordering::Less);
+
+        let mut trades = Vec::new();
+        for trade in [worse, same_output, best, cheaper] {
+            sorted_insert(&mut trades, trade, 3, trade_comparator);
+        }
+        assert_eq!(trades.len(), 3);
+        assert_eq!(trades[0].input_amount().quotient(), BigInt::from(999));
+        assert_eq!(trades[1].hops(), 1);
+        assert_eq!(trades[2].hops(), 2);
+    }
+}
@@ -0,0 +1,428 @@
+use crate::prelude::*;
+use crate::utils::CastFrom;
+use alloy_primitives::ChainId;
+use derive_more::Deref;
+use fastnum::I1024;
+
+/// The share token of an ERC-4626 vault, such as sDAI, together with its underlying asset.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Deref)]
+pub struct VaultShare {
+    /// The ERC-20 share token issued by the vault
+    #[deref]
+    pub token: Token,
+    /// The underlying asset the vault deposits into
+    pub asset: Token,
+}
+
+impl VaultShare {
+    /// Creates a new [`VaultShare`] from the share token and the underlying asset.
+    #[inline]
+    #[must_use]
+    pub const fn new(token: Token, asset: Token) -> Self {
+        Self { token, asset }
+    }
+}
+
+macro_rules! impl_base_currency {
+    ($($share:ty),*) => {
+        $(
+            impl BaseCurrencyCore for $share {
+                #[inline]
+                fn is_native(&self) -> bool {
+                    false
+                }
+
+                #[inline]
+                fn is_token(&self) -> bool {
+                    true
+                }
+
+                #[inline]
+                fn chain_id(&self) -> ChainId {
+                    self.token.chain_id
+                }
+
+                #[inline]
+                fn decimals(&self) -> u8 {
+                    self.token.decimals
+                }
+
+                #[inline]
+                fn symbol(&self) -> Option<&String> {
+                    self.token.symbol.as_ref()
+                }
+
+                #[inline]
+                fn name(&self) -> Option<&String> {
+                    self.token.name.as_ref()
+                }
+            }
+
+            impl BaseCurrency for $share {
+                #[inline]
+                fn equals(&self, other: &impl BaseCurrency) -> bool {
+                    self.token.equals(other)
+                }
+
+                #[inline]
+                fn wrapped(&self) -> &Token {
+                    &self.token
+                }
+            }
+        )*
+    };
+}
+
+impl_base_currency!(VaultShare, &VaultShare);
+
+/// A snapshot of an ERC-4626 vault used to convert between shares and assets.
+///
+/// Conversions follow the rounding rules of the standard, always rounding in favor of the vault:
+/// [`Vault::preview_deposit`] and [`Vault::preview_redeem`] round down, while
+/// [`Vault::preview_mint`] and [`Vault::preview_withdraw`] round up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vault {
+    /// The share currency of the vault
+    pub share: VaultShare,
+    /// The value of `totalAssets()`
+    pub total_assets: BigInt,
+    /// The value of `totalSupply()`
+    pub total_supply: BigInt,
+}
+
+impl Vault {
+    /// Creates a new [`Vault`] snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `share`: The share currency of the vault
+    /// * `total_assets`: The raw amount of underlying assets managed by the vault
+    /// * `total_supply`: The raw amount of shares in circulation
+    ///
+    /// Returns [`Error::Negative`] if either total is negative.
+    #[inline]
+    pub fn new(
+        share: VaultShare,
+        total_assets: impl Into<BigInt>,
+        total_supply: impl Into<BigInt>,
+    ) -> Result<Self, Error> {
+        let total_assets = total_assets.into();
+        let total_supply = total_supply.into();
+        if total_assets.is_negative() || total_supply.is_negative() {
+            return Err(Error::Negative);
+        }
+        Ok(Self {
+            share,
+            total_assets,
+            total_supply,
+        })
+    }
+
+    /// Returns the amount of assets a single raw share is worth.
+    #[inline]
+    #[must_use]
+    pub fn share_price(&self) -> Price<VaultShare, Token> {
+        if self.total_supply == BigInt::ZERO {
+            Price::new(self.share.clone(), self.share.asset.clone(), 1, 1)
+        } else {
+            Price::new(
+                self.share.clone(),
+                self.share.asset.clone(),
+                self.total_supply,
+                self.total_assets,
+            )
+        }
+    }
+
+    /// Returns the amount of shares the vault would exchange for the amount of assets, rounding
+    /// down.
+    #[inline]
+    pub fn convert_to_shares(
+        &self,
+        assets: &CurrencyAmount<Token>,
+    ) -> Result<CurrencyAmount<VaultShare>, Error> {
+        self.to_shares(assets, Rounding::RoundDown)
+    }
+
+    /// Returns the amount of assets the vault would exchange for the amount of shares, rounding
+    /// down.
+    #[inline]
+    pub fn convert_to_assets(
+        &self,
+        shares: &CurrencyAmount<VaultShare>,
+    ) -> Result<CurrencyAmount<Token>, Error> {
+        self.to_assets(shares, Rounding::RoundDown)
+    }
+
+    /// Returns the amount of shares minted by depositing the amount of assets, rounding down.
+    #[inline]
+    pub fn preview_deposit(
+        &self,
+        assets: &CurrencyAmount<Token>,
+    ) -> Result<CurrencyAmount<VaultShare>, Error> {
+        self.to_shares(assets, Rounding::RoundDown)
+    }
+
+    /// Returns the amount of assets required to mint the amount of shares, rounding up.
+    #[inline]
+    pub fn preview_mint(
+        &self,
+        shares: &CurrencyAmount<VaultShare>,
+    ) -> Result<CurrencyAmount<Token>, Error> {
+        self.to_assets(shares, Rounding::RoundUp)
+    }
+
+    /// Returns the amount of shares burned by withdrawing the amount of assets, rounding up.
+    #[inline]
+    pub fn preview_withdraw(
+        &self,
+        assets: &CurrencyAmount<Token>,
+    ) -> Result<CurrencyAmount<VaultShare>, Error> {
+        self.to_shares(assets, Rounding::RoundUp)
+    }
+
+    /// Returns the amount of assets received by redeeming the amount of shares, rounding down.
+    #[inline]
+    pub fn preview_redeem(
+        &self,
+        shares: &CurrencyAmount<VaultShare>,
+    ) -> Result<CurrencyAmount<Token>, Error> {
+        self.to_assets(shares, Rounding::RoundDown)
+    }
+
+    #[inline]
+    fn to_shares(
+        &self,
+        assets: &CurrencyAmount<Token>,
+        rounding: Rounding,
+    ) -> Result<CurrencyAmount<VaultShare>, Error> {
+        if !assets.currency.equals(&self.share.asset) {
+            return Err(Error::CurrencyMismatch);
+        }
+        let shares = if self.total_supply == BigInt::ZERO {
+            assets.quotient()
+        } else if self.total_assets == BigInt::ZERO {
+            // a vault with shares but no assets can not price new shares
+            return Err(Error::DivisionByZero);
+        } else {
+            mul_div(
+                assets.quotient(),
+                self.total_supply,
+                self.total_assets,
+                rounding,
+            )?
+        };
+        CurrencyAmount::from_raw_amount(self.share.clone(), shares)
+    }
+
+    #[inline]
+    fn to_assets(
+        &self,
+        shares: &CurrencyAmount<VaultShare>,
+        rounding: Rounding,
+    ) -> Result<CurrencyAmount<Token>, Error> {
+        if !shares.currency.equals(&self.share) {
+            return Err(Error::CurrencyMismatch);
+        }
+        let assets = if self.total_supply == BigInt::ZERO {
+            shares.quotient()
+        } else {
+            mul_div(
+                shares.quotient(),
+                self.total_assets,
+                self.total_supply,
+                rounding,
+            )?
+        };
+        CurrencyAmount::from_raw_amount(self.share.asset.clone(), assets)
+    }
+}
+
+/// Computes `x * y / denominator` without intermediate overflow, rounding up if requested and
+/// down otherwise.
+///
+/// Returns [`Error::UintOverflow`] if the result does not fit in a [`BigInt`].
+#[inline]
+fn mul_div(x: BigInt, y: BigInt, denominator: BigInt, rounding: Rounding) -> Result<BigInt, Error> {
+    let product = I1024::cast_from(x) * I1024::cast_from(y);
+    let denominator = I1024::cast_from(denominator);
+    let mut quotient = product / denominator;
+    if rounding == Rounding::RoundUp && product % denominator != I1024::ZERO {
+        quotient += I1024::ONE;
+    }
+    if quotient > I1024::cast_from(BigInt::MAX) || quotient < I1024::cast_from(BigInt::MIN) {
+        return Err(Error::UintOverflow);
+    }
+    Ok(BigInt::cast_from(quotient))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref DAI: Token = token!(1, "6B175474E89094C44Da98b954EedeAC495271d0F", 18, "DAI");
+        static ref SDAI: VaultShare = VaultShare::new(
+            token!(1, "83F20F44975D03b1b09e64809B757c47f942BEeA", 18, "sDAI"),
+            DAI.clone()
+        );
+        static ref VAULT: Vault = Vault::new(SDAI.clone(), 1100, 1000).unwrap();
+    }
+
+    fn assets(raw: i64) -> CurrencyAmount<Token> {
+        CurrencyAmount::from_raw_amount(DAI.clone(), raw).unwrap()
+    }
+
+    fn shares(raw: i64) -> CurrencyAmount<VaultShare> {
+        CurrencyAmount::from_raw_amount(SDAI.clone(), raw).unwrap()
+    }
+
+    #[test]
+    fn test_base_currency() {
+        assert!(SDAI.is_token());
+        assert!(SDAI.equals(&SDAI.token));
+        assert!(!SDAI.equals(&DAI.clone()));
+        assert_eq!(SDAI.address(), SDAI.token.address);
+        assert_eq!(SDAI.currency_id(), SDAI.token.currency_id());
+    }
+
+    #[test]
+    fn test_rounding() {
+        // 100 * 1000 / 1100 = 90.9
+        assert_eq!(
+            VAULT.preview_deposit(&assets(100)).unwrap().quotient(),
+            BigInt::from(90)
+        );
+        assert_eq!(
+            VAULT.preview_withdraw(&assets(100)).unwrap().quotient(),
+            BigInt::from(91)
+        );
+        // 91 * 1100 / 1000 = 100.1
+        assert_eq!(
+            VAULT.preview_redeem(&shares(91)).unwrap().quotient(),
+            BigInt::from(100)
+        );
+        assert_eq!(
+            VAULT.preview_mint(&shares(91)).unwrap().quotient(),
+            BigInt::from(101)
+        );
+        assert_eq!(
+            VAULT.convert_to_shares(&assets(100)).unwrap(),
+            VAULT.preview_deposit(&assets(100)).unwrap()
+        );
+        assert_eq!(
+            VAULT.convert_to_assets(&shares(91)).unwrap(),
+            VAULT.preview_redeem(&shares(91)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_exact_conversion_does_not_round() {
+        assert_eq!(
+            VAULT.preview_mint(&shares(1000)).unwrap().quotient(),
+            BigInt::from(1100)
+        );
+        assert_eq!(
+            VAULT.preview_withdraw(&assets(1100)).unwrap().quotient(),
+            BigInt::from(1000)
+        );
+    }
+
+    #[test]
+    fn test_empty_vault() {
+        let vault = Vault::new(SDAI.clone(), 0, 0).unwrap();
+        assert_eq!(
+            vault.preview_deposit(&assets(100)).unwrap().quotient(),
+            BigInt::from(100)
+        );
+        assert_eq!(
+            vault.preview_mint(&shares(100)).unwrap().quotient(),
+            BigInt::from(100)
+        );
+        assert_eq!(vault.share_price().to_significant(5, None).unwrap(), "1");
+    }
+
+    #[test]
+    fn test_share_price() {
+        let price = VAULT.share_price();
+        assert_eq!(price.to_significant(5, None).unwrap(), "1.1");
+        assert_eq!(
+            price.quote(&shares(1000)).unwrap().quotient(),
+            BigInt::from(1100)
+        );
+    }
+
+    #[test]
+    fn test_large_amounts() {
+        let vault = Vault::new(SDAI.clone(), MAX_UINT256, MAX_UINT256 - BigInt::ONE).unwrap();
+        let amount =
+            CurrencyAmount::from_raw_amount(DAI.clone(), MAX_UINT256 - BigInt::ONE).unwrap();
+        assert_eq!(
+            vault.preview_deposit(&amount).unwrap().quotient(),
+            MAX_UINT256 - BigInt::from(2)
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        // 2^200 shares worth 2^400 raw assets each would wrap around to zero in 512 bits
+        let vault = Vault::new(SDAI.clone(), BigInt::ONE << 400, 1).unwrap();
+        let amount = CurrencyAmount::from_raw_amount(SDAI.clone(), BigInt::ONE << 200).unwrap();
+        assert_eq!(vault.preview_redeem(&amount), Err(Error::UintOverflow));
+        assert_eq!(
+            mul_div(
+                BigInt::MAX,
+                BigInt::from(2),
+                BigInt::from(2),
+                Rounding::RoundUp
+            ),
+            Ok(BigInt::MAX)
+        );
+        assert_eq!(
+            mul_div(
+                BigInt::MAX,
+                BigInt::from(2),
+                BigInt::from(3),
+                Rounding::RoundUp
+            ),
+            Ok(BigInt::MAX / BigInt::from(3) * BigInt::from(2) + BigInt::ONE)
+        );
+    }
+
+    #[test]
+    fn test_negative_totals() {
+        assert_eq!(Vault::new(SDAI.clone(), -1, 1000), Err(Error::Negative));
+        assert_eq!(Vault::new(SDAI.clone(), 1100, -1), Err(Error::Negative));
+    }
+
+    #[test]
+    fn test_insolvent_vault() {
+        let vault = Vault::new(SDAI.clone(), 0, 1000).unwrap();
+        assert_eq!(
+            vault.preview_deposit(&assets(100)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            vault.preview_redeem(&shares(100)).unwrap().quotient(),
+            BigInt::ZERO
+        );
+    }
+
+    #[test]
+    fn test_currency_mismatch() {
+        assert_eq!(
+            VAULT.preview_deposit(&CurrencyAmount::from_raw_amount(SDAI.token.clone(), 1).unwrap()),
+            Err(Error::CurrencyMismatch)
+        );
+        let other = VaultShare::new(
+            token!(1, "0000000000000000000000000000000000000001", 18),
+            DAI.clone(),
+        );
+        assert_eq!(
+            VAULT.preview_redeem(&CurrencyAmount::from_raw_amount(other, 1).unwrap()),
+            Err(Error::CurrencyMismatch)
+        );
+    }
+}
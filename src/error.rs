@@ -1,4 +1,14 @@
+use alloy_primitives::{Address, U256};
+use fastnum::{
+    bint::UInt,
+    decimal::{Context, Sign},
+    D256,
+};
+
 /// Custom error types that are used throughout the SDK to handle various error conditions.
+///
+/// Every variant has a stable numeric [`code`](Error::code), e.g. for bindings that cannot match
+/// on the variants.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, thiserror::Error)]
 pub enum Error {
     /// Triggers when the compared chain IDs do not match.
@@ -20,11 +30,159 @@ pub enum Error {
     /// Triggers when the value is invalid.
     #[error("{0}")]
     Invalid(&'static str),
+
+    /// Triggers when the chain ID is zero.
+    #[error("chain ID can't be zero")]
+    ZeroChainId,
+
+    /// Triggers when the address is the zero address.
+    #[error("address is zero")]
+    ZeroAddress,
+
+    /// Triggers when a string is not a valid address.
+    #[error("invalid address")]
+    InvalidAddress,
+
+    /// Triggers when a mixed-case address does not match its checksum.
+    #[error("address checksum mismatch, expected {0}")]
+    ChecksumMismatch(Address),
+
+    /// Triggers when a fee in basis points exceeds 100%.
+    #[error("fee of {0} bps exceeds 10000 bps")]
+    FeeBpsOutOfRange(u64),
+
+    /// Triggers when the number of decimals exceeds the supported maximum.
+    #[error("decimals {decimals} exceed the maximum of {max}")]
+    DecimalsOutOfRange { decimals: u8, max: u8 },
+
+    /// Triggers when a mid price is zero or has a zero denominator, leaving the price impact
+    /// undefined.
+    #[error("mid price quotes a zero amount")]
+    ZeroQuote,
+
+    /// Triggers when a fraction would have a zero denominator.
+    #[error("division by zero")]
+    DivisionByZero,
+
+    /// Triggers when a string does not parse, at the byte offset of the first offending character.
+    #[error("invalid {what} at position {position}")]
+    Parse {
+        /// What the string should represent, e.g. `"address"`.
+        what: &'static str,
+        /// The byte offset in the string, which is its length if it ends prematurely.
+        position: usize,
+    },
+
+    /// Triggers when a chain ID is not supported by the SDK or has no entry in a table.
+    #[error("unsupported chain ID {0}")]
+    UnsupportedChainId(u64),
+
+    /// Triggers when there is no wrapped native token on the chain.
+    #[error("no wrapped native token on chain {0}")]
+    MissingWrappedNative(u64),
+
+    /// Triggers when a number is formatted with zero significant digits.
+    #[error("significant digits can't be zero")]
+    ZeroSignificantDigits,
+
+    /// Triggers when a value that must not be negative is negative.
+    #[error("value can't be negative")]
+    Negative,
+
+    /// Triggers when a collection that needs at least one element is empty.
+    #[error("no elements")]
+    Empty,
+
+    /// Triggers when a balance is too small for a debit.
+    #[error("insufficient balance: {available} available, {required} required")]
+    InsufficientBalance {
+        /// The balance, in raw units.
+        available: U256,
+        /// The amount debited, in raw units.
+        required: U256,
+    },
+
+    /// Triggers when a value exceeds its maximum.
+    #[error("{what} {value} exceeds the maximum of {max}")]
+    OutOfRange {
+        /// What the value is, e.g. `"precision"`.
+        what: &'static str,
+        /// The value, where percentages are fractions of one.
+        value: D256,
+        /// The maximum, in the same unit as the value.
+        max: D256,
+    },
+
+    /// Triggers when the pools of a route do not connect its input to its output.
+    #[error("invalid path")]
+    InvalidPath,
+
+    /// Triggers when a number of periods is zero.
+    #[error("number of periods can't be zero")]
+    ZeroPeriods,
+
+    /// Triggers when a fee in pips is not one of the [`FeeAmount`](crate::prelude::FeeAmount)
+    /// tiers.
+    #[error("unknown fee amount of {0} pips")]
+    UnknownFeeAmount(u32),
+
+    /// Triggers when a currency is registered twice.
+    #[error("currency is already registered")]
+    DuplicateCurrency,
+}
+
+impl Error {
+    /// Returns an [`Error::OutOfRange`] for an integer value.
+    #[inline]
+    pub(crate) const fn out_of_range(what: &'static str, value: u64, max: u64) -> Self {
+        const fn decimal(value: u64) -> D256 {
+            D256::from_parts(UInt::from_u64(value), 0, Sign::Plus, Context::default())
+        }
+        Self::OutOfRange {
+            what,
+            value: decimal(value),
+            max: decimal(max),
+        }
+    }
+
+    /// Returns the stable numeric code of the error, which is never reused for another variant.
+    #[inline]
+    #[must_use]
+    pub const fn code(&self) -> u32 {
+        match self {
+            Self::ChainIdMismatch(..) => 1,
+            Self::EqualAddresses => 2,
+            Self::UintOverflow => 3,
+            Self::CurrencyMismatch => 4,
+            Self::Invalid(_) => 5,
+            Self::ZeroChainId => 6,
+            Self::ZeroAddress => 7,
+            Self::InvalidAddress => 8,
+            Self::ChecksumMismatch(_) => 9,
+            Self::FeeBpsOutOfRange(_) => 10,
+            Self::DecimalsOutOfRange { .. } => 11,
+            Self::ZeroQuote => 12,
+            Self::DivisionByZero => 13,
+            Self::Parse { .. } => 14,
+            Self::UnsupportedChainId(_) => 15,
+            Self::MissingWrappedNative(_) => 16,
+            Self::ZeroSignificantDigits => 17,
+            Self::Negative => 18,
+            Self::Empty => 19,
+            Self::InsufficientBalance { .. } => 20,
+            Self::OutOfRange { .. } => 21,
+            Self::InvalidPath => 22,
+            Self::ZeroPeriods => 23,
+            Self::UnknownFeeAmount(_) => 24,
+            Self::DuplicateCurrency => 25,
+        }
+    }
 }
 
 #[cfg(all(feature = "std", test))]
 mod tests {
     use super::*;
+    use fastnum::dec256;
 
     #[test]
     fn test_chain_id_mismatch_error() {
@@ -55,4 +213,111 @@ mod tests {
         let error = Error::Invalid("invalid");
         assert_eq!(error.to_string(), "invalid");
     }
+
+    #[test]
+    fn test_checksum_mismatch_error() {
+        let error = Error::ChecksumMismatch(alloy_primitives::address!(
+            "6B175474E89094C44Da98b954EedeAC495271d0F"
+        ));
+        assert_eq!(
+            error.to_string(),
+            "address checksum mismatch, expected 0x6B175474E89094C44Da98b954EedeAC495271d0F"
+        );
+    }
+
+    #[test]
+    fn test_decimals_out_of_range_error() {
+        let error = Error::DecimalsOutOfRange {
+            decimals: 78,
+            max: 77,
+        };
+        assert_eq!(error.to_string(), "decimals 78 exceed the maximum of 77");
+    }
+
+    #[test]
+    fn test_zero_quote_error() {
+        let error = Error::ZeroQuote;
+        assert_eq!(error.to_string(), "mid price quotes a zero amount");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Error::Parse {
+            what: "percent",
+            position: 3,
+        };
+        assert_eq!(error.to_string(), "invalid percent at position 3");
+    }
+
+    #[test]
+    fn test_unsupported_chain_id_error() {
+        let error = Error::UnsupportedChainId(12345);
+        assert_eq!(error.to_string(), "unsupported chain ID 12345");
+        assert_eq!(error.code(), 15);
+    }
+
+    #[test]
+    fn test_structured_errors() {
+        let error = Error::InsufficientBalance {
+            available: U256::from(1),
+            required: U256::from(2),
+        };
+        assert_eq!(
+            error.to_string(),
+            "insufficient balance: 1 available, 2 required"
+        );
+        let error = Error::OutOfRange {
+            what: "slippage tolerance",
+            value: dec256!(1.5),
+            max: D256::ONE,
+        };
+        assert_eq!(
+            error.to_string(),
+            "slippage tolerance 1.5 exceeds the maximum of 1"
+        );
+        assert_eq!(error.code(), 21);
+    }
+
+    #[test]
+    fn test_codes_are_unique() {
+        let errors = [
+            Error::ChainIdMismatch(1, 2),
+            Error::EqualAddresses,
+            Error::UintOverflow,
+            Error::CurrencyMismatch,
+            Error::Invalid("invalid"),
+            Error::ZeroChainId,
+            Error::ZeroAddress,
+            Error::InvalidAddress,
+            Error::ChecksumMismatch(Address::ZERO),
+            Error::FeeBpsOutOfRange(10_001),
+            Error::DecimalsOutOfRange {
+                decimals: 78,
+                max: 77,
+            },
+            Error::ZeroQuote,
+            Error::DivisionByZero,
+            Error::Parse {
+                what: "address",
+                position: 0,
+            },
+            Error::UnsupportedChainId(12345),
+            Error::MissingWrappedNative(12345),
+            Error::ZeroSignificantDigits,
+            Error::Negative,
+            Error::Empty,
+            Error::InsufficientBalance {
+                available: U256::from(1),
+                required: U256::from(2),
+            },
+            Error::out_of_range("precision", 61, 60),
+            Error::InvalidPath,
+            Error::ZeroPeriods,
+            Error::UnknownFeeAmount(501),
+            Error::DuplicateCurrency,
+        ];
+        for (i, error) in errors.iter().enumerate() {
+            assert_eq!(error.code(), i as u32 + 1);
+        }
+    }
 }
@@ -0,0 +1,184 @@
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+use serde::Serialize;
+
+/// The version of the layout of [`Document`], bumped whenever a field is renamed or removed.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// The built-in address and token tables, keyed by chain ID.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Document {
+    /// The [`FORMAT_VERSION`] of the document.
+    pub version: u32,
+    /// The version of the SDK the tables come from.
+    pub sdk_version: &'static str,
+    /// The tables of each chain.
+    pub chains: BTreeMap<String, ChainTables>,
+}
+
+/// The entries of the tables for one chain.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ChainTables {
+    /// The name of the chain, e.g. `"arbitrum-one"`, if it is a [`ChainId`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The contracts of the chain in [`CHAIN_TO_ADDRESSES_MAP`], by their
+    /// [`ChainAddresses::CONTRACT_NAMES`].
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub deployments: BTreeMap<&'static str, String>,
+    /// The addresses of the chain in each [`AddressMapKind`], by the name of the map.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub address_maps: BTreeMap<&'static str, String>,
+    /// The wrapped native token of the chain in [`WRAPPED_NATIVE_TOKENS`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wrapped_native: Option<WrappedNativeToken>,
+}
+
+/// A [`WrappedNative`] token with its decimals.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct WrappedNativeToken {
+    /// The checksummed address of the token.
+    pub address: String,
+    /// The decimals of the token.
+    pub decimals: u8,
+    /// The symbol of the token.
+    pub symbol: &'static str,
+    /// The name of the token.
+    pub name: &'static str,
+}
+
+impl Document {
+    /// Collects the tables of the SDK. Addresses are checksummed and chain IDs are decimal strings,
+    /// since neither JSON nor TOML has integer keys.
+    #[inline]
+    #[must_use]
+    pub fn sdk() -> Self {
+        let mut chains: BTreeMap<u64, ChainTables> = BTreeMap::new();
+        for (chain_id, addresses) in CHAIN_TO_ADDRESSES_MAP.iter() {
+            let deployments = &mut chains.entry(*chain_id).or_default().deployments;
+            for (contract, address) in ChainAddresses::CONTRACT_NAMES
+                .into_iter()
+                .zip(addresses.contracts())
+            {
+                if let Some(address) = address {
+                    deployments.insert(contract, address.to_string());
+                }
+            }
+        }
+        for kind in AddressMapKind::ALL {
+            for (chain_id, address) in kind.map().iter() {
+                chains
+                    .entry(*chain_id)
+                    .or_default()
+                    .address_maps
+                    .insert(kind.name(), address.to_string());
+            }
+        }
+        for (chain_id, wrapped) in WRAPPED_NATIVE_TOKENS.iter() {
+            chains.entry(*chain_id).or_default().wrapped_native = Some(WrappedNativeToken {
+                address: wrapped.address.to_string(),
+                decimals: WrappedNative::DECIMALS,
+                symbol: wrapped.symbol,
+                name: wrapped.name,
+            });
+        }
+        Self {
+            version: FORMAT_VERSION,
+            sdk_version: env!("CARGO_PKG_VERSION"),
+            chains: chains
+                .into_iter()
+                .map(|(chain_id, mut tables)| {
+                    tables.name = ChainId::from_id(chain_id).map(|chain| chain.to_string());
+                    (chain_id.to_string(), tables)
+                })
+                .collect(),
+        }
+    }
+
+    /// Serializes the document as pretty-printed JSON.
+    #[inline]
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("the document only has string keys")
+    }
+
+    /// Serializes the document as TOML, with a table per chain.
+    #[inline]
+    #[must_use]
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("the document has no null values")
+    }
+}
+
+/// Returns the tables of the SDK as a JSON document.
+#[inline]
+#[must_use]
+pub fn to_json() -> String {
+    Document::sdk().to_json()
+}
+
+/// Returns the tables of the SDK as a TOML document.
+#[inline]
+#[must_use]
+pub fn to_toml() -> String {
+    Document::sdk().to_toml()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document() {
+        let document = Document::sdk();
+        assert_eq!(document.version, FORMAT_VERSION);
+        for chain_id in CHAIN_TO_ADDRESSES_MAP
+            .keys()
+            .chain(WRAPPED_NATIVE_TOKENS.keys())
+        {
+            assert!(document.chains.contains_key(&chain_id.to_string()));
+        }
+
+        let mainnet = &document.chains["1"];
+        assert_eq!(mainnet.name.as_deref(), Some("mainnet"));
+        assert_eq!(
+            mainnet.deployments["v3_core_factory"],
+            "0x1F98431c8aD98523631AE4a59f267346ea31F984"
+        );
+        assert_eq!(
+            mainnet.address_maps["V2_ROUTER_ADDRESSES"],
+            V2_ROUTER_ADDRESS.to_string()
+        );
+        assert_eq!(
+            mainnet.wrapped_native.as_ref().unwrap().symbol,
+            WRAPPED_NATIVE_TOKENS[&1].symbol
+        );
+        for kind in AddressMapKind::ALL {
+            for (chain_id, address) in kind.map().iter() {
+                assert_eq!(
+                    document.chains[&chain_id.to_string()].address_maps[kind.name()],
+                    address.to_string()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_formats() {
+        let document = Document::sdk();
+        let json: serde_json::Value = serde_json::from_str(&document.to_json()).unwrap();
+        let toml: toml::Table = document.to_toml().parse().unwrap();
+        assert_eq!(json["version"], FORMAT_VERSION);
+        assert_eq!(toml["version"].as_integer(), Some(FORMAT_VERSION.into()));
+        assert_eq!(
+            json["chains"]["8453"]["wrapped_native"]["address"],
+            toml["chains"]["8453"]["wrapped_native"]["address"]
+                .as_str()
+                .unwrap()
+        );
+        assert_eq!(
+            json["chains"].as_object().unwrap().len(),
+            toml["chains"].as_table().unwrap().len()
+        );
+    }
+}
@@ -22,6 +22,9 @@ use num_traits as _;
 
 /// Contains functionality related to All Contracts deployed and supported by the Uniswap SDK.
 pub mod addresses;
+/// Contains the C bindings of the Uniswap SDK Core.
+#[cfg(feature = "capi")]
+pub mod capi;
 /// Contains functionality related to All Contracts deployed and supported by the Uniswap SDK.
 pub mod chains;
 /// Contains some constants and enums used in the Uniswap SDK Core
@@ -33,8 +36,19 @@ pub mod entities;
 /// This module defines custom error types that are used throughout the SDK to
 /// handle various error conditions.
 pub mod error;
+/// Contains the export of the address and token tables as JSON and TOML.
+#[cfg(feature = "export")]
+pub mod export;
+/// Contains the Python bindings of the Uniswap SDK Core.
+#[cfg(feature = "python")]
+pub mod python;
 /// Contains utility functions and helpers used across the Uniswap SDK Core.
 pub mod utils;
+/// Contains checks of the consistency of the address tables.
+pub mod verify;
+/// Contains the JavaScript bindings of the Uniswap SDK Core.
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 /// Contains commonly used items from the Uniswap SDK Core.
 ///
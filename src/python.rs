@@ -0,0 +1,874 @@
+//! Python bindings generated with [`pyo3`].
+//!
+//! Amounts cross the boundary as Python `int`s of arbitrary size, so results match the Rust code
+//! exactly. Errors are raised as `ValueError`s carrying the message of the [`Error`], and
+//! division by zero as `ZeroDivisionError`.
+//!
+//! The extension module is built by linking [`uniswap_sdk_core`] into a `cdylib`, e.g. with
+//! maturin and `features = ["python", "pyo3/extension-module"]`.
+
+use crate::prelude::*;
+use core::{
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+use pyo3::{
+    basic::CompareOp,
+    exceptions::{PyTypeError, PyValueError, PyZeroDivisionError},
+    prelude::*,
+    types::{PyDict, PyInt},
+};
+use std::collections::hash_map::DefaultHasher;
+
+impl From<Error> for PyErr {
+    #[inline]
+    fn from(error: Error) -> Self {
+        match error {
+            Error::DivisionByZero => PyZeroDivisionError::new_err(error.to_string()),
+            _ => PyValueError::new_err(error.to_string()),
+        }
+    }
+}
+
+/// Converts a Python `int` into a [`BigInt`].
+fn to_big_int(value: &Bound<'_, PyAny>) -> PyResult<BigInt> {
+    let value = value
+        .cast::<PyInt>()
+        .map_err(|_| PyTypeError::new_err("expected an int"))?;
+    Ok(parse_big_int(&value.str()?.to_cow()?)?)
+}
+
+/// Converts a [`BigInt`] into a Python `int`.
+fn to_py_int<'py>(py: Python<'py>, value: &BigInt) -> PyResult<Bound<'py, PyAny>> {
+    py.get_type::<PyInt>().call1((value.to_string(),))
+}
+
+/// Returns a non-zero denominator.
+fn to_denominator(value: &Bound<'_, PyAny>) -> PyResult<BigInt> {
+    let denominator = to_big_int(value)?;
+    if denominator == BigInt::ZERO {
+        return Err(PyZeroDivisionError::new_err("denominator is zero"));
+    }
+    Ok(denominator)
+}
+
+/// Converts a `Fraction`, `Percent` or `int` operand into a [`Fraction`].
+fn to_fraction(value: &Bound<'_, PyAny>) -> PyResult<Fraction> {
+    if let Ok(fraction) = value.cast::<PyFraction>() {
+        Ok(fraction.get().0.clone())
+    } else if let Ok(percent) = value.cast::<PyPercent>() {
+        Ok(percent.get().0.as_fraction())
+    } else {
+        Ok(Fraction::new(to_big_int(value)?, 1))
+    }
+}
+
+/// Returns a non-zero divisor.
+fn to_divisor(value: &Bound<'_, PyAny>) -> PyResult<Fraction> {
+    let divisor = to_fraction(value)?;
+    if divisor.numerator == BigInt::ZERO {
+        return Err(PyZeroDivisionError::new_err("division by zero"));
+    }
+    Ok(divisor)
+}
+
+fn compare<T: Ord>(a: &T, b: &T, op: CompareOp) -> bool {
+    op.matches(a.cmp(b))
+}
+
+/// Hashes a fraction like the equal `fractions.Fraction`, which is consistent with `int`s.
+fn hash_fraction(py: Python<'_>, fraction: &Fraction) -> PyResult<isize> {
+    py.import("fractions")?
+        .getattr("Fraction")?
+        .call1((
+            to_py_int(py, &fraction.numerator)?,
+            to_py_int(py, &fraction.denominator)?,
+        ))?
+        .hash()
+}
+
+fn hash(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// An ERC20 token.
+#[pyclass(
+    name = "Token",
+    module = "uniswap_sdk_core",
+    frozen,
+    skip_from_py_object
+)]
+#[derive(Clone, Debug)]
+pub struct PyToken(pub Token);
+
+#[pymethods]
+impl PyToken {
+    #[new]
+    #[pyo3(signature = (chain_id, address, decimals, symbol=None, name=None))]
+    fn new(
+        chain_id: u64,
+        address: &str,
+        decimals: u8,
+        symbol: Option<&str>,
+        name: Option<&str>,
+    ) -> PyResult<Self> {
+        let mut builder = Token::builder(chain_id)
+            .address_str(address)
+            .decimals(decimals);
+        if let Some(symbol) = symbol {
+            builder = builder.symbol(symbol);
+        }
+        if let Some(name) = name {
+            builder = builder.name(name);
+        }
+        Ok(Self(builder.build()?))
+    }
+
+    #[getter]
+    fn chain_id(&self) -> u64 {
+        self.0.chain_id()
+    }
+
+    /// The checksummed address.
+    #[getter]
+    fn address(&self) -> String {
+        self.0.address().to_string()
+    }
+
+    #[getter]
+    fn decimals(&self) -> u8 {
+        self.0.decimals()
+    }
+
+    #[getter]
+    fn symbol(&self) -> Option<String> {
+        self.0.symbol.clone()
+    }
+
+    #[getter]
+    fn name(&self) -> Option<String> {
+        self.0.name.clone()
+    }
+
+    fn sorts_before(&self, other: &Self) -> PyResult<bool> {
+        Ok(self.0.sorts_before(&other.0)?)
+    }
+
+    /// Returns the token as a `Currency`.
+    fn as_currency(&self) -> PyCurrency {
+        PyCurrency(self.0.clone().into())
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0.equals(&other.0)
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash(&(self.0.chain_id, self.0.address()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Token({}, '{}', {}, {:?})",
+            self.0.chain_id,
+            self.0.address(),
+            self.0.decimals,
+            self.0.symbol.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+/// A token or the native currency of a chain.
+#[pyclass(
+    name = "Currency",
+    module = "uniswap_sdk_core",
+    frozen,
+    skip_from_py_object
+)]
+#[derive(Clone, Debug)]
+pub struct PyCurrency(pub Currency);
+
+#[pymethods]
+impl PyCurrency {
+    /// Returns the native currency of the chain.
+    #[staticmethod]
+    fn native(chain_id: u64) -> Self {
+        Self(Ether::on_chain(chain_id).into())
+    }
+
+    #[staticmethod]
+    fn token(token: &PyToken) -> Self {
+        Self(token.0.clone().into())
+    }
+
+    #[getter]
+    fn is_native(&self) -> bool {
+        self.0.is_native()
+    }
+
+    #[getter]
+    fn chain_id(&self) -> u64 {
+        self.0.chain_id()
+    }
+
+    #[getter]
+    fn decimals(&self) -> u8 {
+        self.0.decimals()
+    }
+
+    #[getter]
+    fn symbol(&self) -> Option<String> {
+        self.0.symbol().cloned()
+    }
+
+    #[getter]
+    fn name(&self) -> Option<String> {
+        self.0.name().cloned()
+    }
+
+    /// The token itself, or the wrapped native token.
+    #[getter]
+    fn wrapped(&self) -> Option<PyToken> {
+        match &self.0 {
+            Currency::Token(token) => Some(PyToken(token.clone())),
+            Currency::NativeCurrency(ether) => WETH9::on_chain(ether.chain_id()).map(PyToken),
+        }
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0.equals(&other.0)
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash(&self.0.currency_id())
+    }
+
+    fn __repr__(&self) -> String {
+        match &self.0 {
+            Currency::Token(token) => {
+                format!("Currency.token({})", PyToken(token.clone()).__repr__())
+            }
+            Currency::NativeCurrency(ether) => format!("Currency.native({})", ether.chain_id()),
+        }
+    }
+}
+
+/// An exact rational number.
+#[pyclass(
+    name = "Fraction",
+    module = "uniswap_sdk_core",
+    frozen,
+    skip_from_py_object
+)]
+#[derive(Clone, Debug)]
+pub struct PyFraction(pub Fraction);
+
+#[pymethods]
+impl PyFraction {
+    #[new]
+    #[pyo3(signature = (numerator, denominator=None))]
+    fn new(numerator: &Bound<'_, PyAny>, denominator: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let denominator = match denominator {
+            Some(denominator) => to_denominator(denominator)?,
+            None => BigInt::ONE,
+        };
+        Ok(Self(Fraction::new(to_big_int(numerator)?, denominator)))
+    }
+
+    #[getter]
+    fn numerator<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        to_py_int(py, &self.0.numerator)
+    }
+
+    #[getter]
+    fn denominator<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        to_py_int(py, &self.0.denominator)
+    }
+
+    /// The integer part, rounded towards zero.
+    #[getter]
+    fn quotient<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        to_py_int(py, &self.0.quotient())
+    }
+
+    fn invert(&self) -> PyResult<Self> {
+        if self.0.numerator == BigInt::ZERO {
+            return Err(PyZeroDivisionError::new_err("division by zero"));
+        }
+        Ok(Self(self.0.invert()))
+    }
+
+    #[pyo3(signature = (significant_digits, rounding=None))]
+    fn to_significant(
+        &self,
+        significant_digits: u8,
+        rounding: Option<PyRef<'_, PyRounding>>,
+    ) -> PyResult<String> {
+        Ok(self.0.to_significant(
+            significant_digits,
+            rounding.map(|rounding| (*rounding).into()),
+        )?)
+    }
+
+    #[pyo3(signature = (decimal_places, rounding=None))]
+    fn to_fixed(&self, decimal_places: u8, rounding: Option<PyRef<'_, PyRounding>>) -> String {
+        self.0
+            .to_fixed(decimal_places, rounding.map(|rounding| (*rounding).into()))
+    }
+
+    fn __add__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(self.0.clone() + to_fraction(other)?))
+    }
+
+    fn __radd__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(to_fraction(other)? + self.0.clone()))
+    }
+
+    fn __sub__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(self.0.clone() - to_fraction(other)?))
+    }
+
+    fn __rsub__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(to_fraction(other)? - self.0.clone()))
+    }
+
+    fn __mul__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(self.0.clone() * to_fraction(other)?))
+    }
+
+    fn __rmul__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(to_fraction(other)? * self.0.clone()))
+    }
+
+    fn __truediv__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(self.0.clone() / to_divisor(other)?))
+    }
+
+    fn __rtruediv__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(to_fraction(other)? * self.invert()?.0))
+    }
+
+    fn __neg__(&self) -> Self {
+        Self(Fraction::new(-self.0.numerator, self.0.denominator))
+    }
+
+    fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<bool> {
+        Ok(compare(&self.0, &to_fraction(other)?, op))
+    }
+
+    fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+        hash_fraction(py, &self.0)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Fraction({}, {})", self.0.numerator, self.0.denominator)
+    }
+}
+
+/// A fraction formatted as a percentage.
+#[pyclass(
+    name = "Percent",
+    module = "uniswap_sdk_core",
+    frozen,
+    skip_from_py_object
+)]
+#[derive(Clone, Debug)]
+pub struct PyPercent(pub Percent);
+
+#[pymethods]
+impl PyPercent {
+    #[new]
+    #[pyo3(signature = (numerator, denominator=None))]
+    fn new(numerator: &Bound<'_, PyAny>, denominator: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let denominator = match denominator {
+            Some(denominator) => to_denominator(denominator)?,
+            None => BigInt::ONE,
+        };
+        Ok(Self(Percent::new(to_big_int(numerator)?, denominator)))
+    }
+
+    /// Parses a percentage such as `"0.3%"`, `"30bps"` or `"3000pips"`.
+    #[staticmethod]
+    fn parse(value: &str) -> PyResult<Self> {
+        Ok(Self(Percent::from_str(value)?))
+    }
+
+    #[staticmethod]
+    fn from_bps(bps: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(Percent::from_bps(to_big_int(bps)?)))
+    }
+
+    #[getter]
+    fn numerator<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        to_py_int(py, &self.0.numerator)
+    }
+
+    #[getter]
+    fn denominator<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        to_py_int(py, &self.0.denominator)
+    }
+
+    fn as_fraction(&self) -> PyFraction {
+        PyFraction(self.0.as_fraction())
+    }
+
+    #[pyo3(signature = (significant_digits=5, rounding=None))]
+    fn to_significant(
+        &self,
+        significant_digits: u8,
+        rounding: Option<PyRef<'_, PyRounding>>,
+    ) -> PyResult<String> {
+        Ok(self.0.to_significant(
+            significant_digits,
+            rounding.map(|rounding| (*rounding).into()),
+        )?)
+    }
+
+    #[pyo3(signature = (decimal_places=2, rounding=None))]
+    fn to_fixed(&self, decimal_places: u8, rounding: Option<PyRef<'_, PyRounding>>) -> String {
+        self.0
+            .to_fixed(decimal_places, rounding.map(|rounding| (*rounding).into()))
+    }
+
+    fn __add__(&self, other: &Self) -> Self {
+        Self(self.0.clone() + other.0.clone())
+    }
+
+    fn __sub__(&self, other: &Self) -> Self {
+        Self(self.0.clone() - other.0.clone())
+    }
+
+    fn __mul__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let other = to_fraction(other)?;
+        Ok(Self(
+            self.0.clone() * Percent::new(other.numerator, other.denominator),
+        ))
+    }
+
+    fn __truediv__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let other = to_divisor(other)?;
+        Ok(Self(
+            self.0.clone() / Percent::new(other.numerator, other.denominator),
+        ))
+    }
+
+    fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<bool> {
+        Ok(compare(&self.0.as_fraction(), &to_fraction(other)?, op))
+    }
+
+    fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+        hash_fraction(py, &self.0.as_fraction())
+    }
+
+    fn __str__(&self) -> String {
+        format!("{}%", self.0.to_fixed(2, None))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Percent({}, {})", self.0.numerator, self.0.denominator)
+    }
+}
+
+/// An amount of a currency.
+#[pyclass(
+    name = "CurrencyAmount",
+    module = "uniswap_sdk_core",
+    frozen,
+    skip_from_py_object
+)]
+#[derive(Clone, Debug)]
+pub struct PyCurrencyAmount(pub CurrencyAmount<Currency>);
+
+#[pymethods]
+impl PyCurrencyAmount {
+    /// Creates an amount from the raw amount of the smallest unit of the currency.
+    #[staticmethod]
+    fn from_raw_amount(currency: &PyCurrency, raw_amount: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(CurrencyAmount::from_raw_amount(
+            currency.0.clone(),
+            to_big_int(raw_amount)?,
+        )?))
+    }
+
+    #[staticmethod]
+    fn from_fractional_amount(
+        currency: &PyCurrency,
+        numerator: &Bound<'_, PyAny>,
+        denominator: &Bound<'_, PyAny>,
+    ) -> PyResult<Self> {
+        Ok(Self(CurrencyAmount::from_fractional_amount(
+            currency.0.clone(),
+            to_big_int(numerator)?,
+            to_denominator(denominator)?,
+        )?))
+    }
+
+    #[getter]
+    fn currency(&self) -> PyCurrency {
+        PyCurrency(self.0.currency.clone())
+    }
+
+    /// The raw amount, rounded towards zero.
+    #[getter]
+    fn quotient<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        to_py_int(py, &self.0.quotient())
+    }
+
+    fn as_fraction(&self) -> PyFraction {
+        PyFraction(self.0.as_fraction())
+    }
+
+    fn to_exact(&self) -> String {
+        self.0.to_exact()
+    }
+
+    #[pyo3(signature = (significant_digits=6, rounding=None))]
+    fn to_significant(
+        &self,
+        significant_digits: u8,
+        rounding: Option<PyRef<'_, PyRounding>>,
+    ) -> PyResult<String> {
+        Ok(self.0.to_significant(
+            significant_digits,
+            rounding.map(|rounding| (*rounding).into()),
+        )?)
+    }
+
+    #[pyo3(signature = (decimal_places, rounding=None))]
+    fn to_fixed(
+        &self,
+        decimal_places: u8,
+        rounding: Option<PyRef<'_, PyRounding>>,
+    ) -> PyResult<String> {
+        Ok(self
+            .0
+            .to_fixed(decimal_places, rounding.map(|rounding| (*rounding).into()))?)
+    }
+
+    fn __add__(&self, other: &Self) -> PyResult<Self> {
+        Ok(Self(self.0.add(&other.0)?))
+    }
+
+    fn __sub__(&self, other: &Self) -> PyResult<Self> {
+        Ok(Self(self.0.subtract(&other.0)?))
+    }
+
+    fn __mul__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(self.0.multiply(&to_fraction(other)?)?))
+    }
+
+    fn __rmul__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        self.__mul__(other)
+    }
+
+    fn __truediv__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(self.0.divide(&to_divisor(other)?)?))
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> PyResult<bool> {
+        if !self.0.currency.equals(&other.0.currency) {
+            return Err(Error::CurrencyMismatch.into());
+        }
+        Ok(compare(&self.0.as_fraction(), &other.0.as_fraction(), op))
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_exact()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "CurrencyAmount({}, {})",
+            self.0.to_exact(),
+            self.0.currency.symbol().map_or("", String::as_str)
+        )
+    }
+}
+
+/// The price of a base currency in terms of a quote currency.
+#[pyclass(
+    name = "Price",
+    module = "uniswap_sdk_core",
+    frozen,
+    skip_from_py_object
+)]
+#[derive(Clone, Debug)]
+pub struct PyPrice(pub Price<Currency, Currency>);
+
+#[pymethods]
+impl PyPrice {
+    /// Creates the price at which `denominator` raw base units trade for `numerator` raw quote
+    /// units.
+    #[new]
+    fn new(
+        base_currency: &PyCurrency,
+        quote_currency: &PyCurrency,
+        denominator: &Bound<'_, PyAny>,
+        numerator: &Bound<'_, PyAny>,
+    ) -> PyResult<Self> {
+        Ok(Self(Price::new(
+            base_currency.0.clone(),
+            quote_currency.0.clone(),
+            to_denominator(denominator)?,
+            to_big_int(numerator)?,
+        )))
+    }
+
+    #[staticmethod]
+    fn from_currency_amounts(
+        base_amount: &PyCurrencyAmount,
+        quote_amount: &PyCurrencyAmount,
+    ) -> PyResult<Self> {
+        if base_amount.0.numerator == BigInt::ZERO {
+            return Err(PyZeroDivisionError::new_err("base amount is zero"));
+        }
+        Ok(Self(Price::from_currency_amounts(
+            base_amount.0.clone(),
+            quote_amount.0.clone(),
+        )))
+    }
+
+    #[getter]
+    fn base_currency(&self) -> PyCurrency {
+        PyCurrency(self.0.base_currency.clone())
+    }
+
+    #[getter]
+    fn quote_currency(&self) -> PyCurrency {
+        PyCurrency(self.0.quote_currency.clone())
+    }
+
+    fn invert(&self) -> PyResult<Self> {
+        if self.0.numerator == BigInt::ZERO {
+            return Err(PyZeroDivisionError::new_err("price is zero"));
+        }
+        Ok(Self(self.0.invert()))
+    }
+
+    /// Returns the amount of quote currency corresponding to an amount of base currency.
+    fn quote(&self, amount: &PyCurrencyAmount) -> PyResult<PyCurrencyAmount> {
+        Ok(PyCurrencyAmount(self.0.quote(&amount.0)?))
+    }
+
+    #[pyo3(signature = (significant_digits=6, rounding=None))]
+    fn to_significant(
+        &self,
+        significant_digits: u8,
+        rounding: Option<PyRef<'_, PyRounding>>,
+    ) -> PyResult<String> {
+        Ok(self.0.to_significant(
+            significant_digits,
+            rounding.map(|rounding| (*rounding).into()),
+        )?)
+    }
+
+    #[pyo3(signature = (decimal_places=4, rounding=None))]
+    fn to_fixed(&self, decimal_places: u8, rounding: Option<PyRef<'_, PyRounding>>) -> String {
+        self.0
+            .to_fixed(decimal_places, rounding.map(|rounding| (*rounding).into()))
+    }
+
+    fn __mul__(&self, other: &Self) -> PyResult<Self> {
+        Ok(Self(self.0.multiply(&other.0)?))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Price({} {}/{})",
+            self.0.to_significant(6, None).unwrap_or_default(),
+            self.0.quote_currency.symbol().map_or("", String::as_str),
+            self.0.base_currency.symbol().map_or("", String::as_str)
+        )
+    }
+}
+
+/// The rounding modes of the SDK, see [`Rounding`].
+#[pyclass(
+    name = "Rounding",
+    module = "uniswap_sdk_core",
+    eq,
+    eq_int,
+    frozen,
+    skip_from_py_object
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PyRounding {
+    RoundDown,
+    RoundHalfUp,
+    RoundUp,
+}
+
+impl From<PyRounding> for Rounding {
+    #[inline]
+    fn from(rounding: PyRounding) -> Self {
+        match rounding {
+            PyRounding::RoundDown => Self::RoundDown,
+            PyRounding::RoundHalfUp => Self::RoundHalfUp,
+            PyRounding::RoundUp => Self::RoundUp,
+        }
+    }
+}
+
+/// Returns the percent difference between the mid price and the execution price of a trade.
+#[pyfunction]
+#[pyo3(name = "compute_price_impact")]
+fn py_compute_price_impact(
+    mid_price: &PyPrice,
+    input_amount: &PyCurrencyAmount,
+    output_amount: &PyCurrencyAmount,
+) -> PyResult<PyPercent> {
+    Ok(PyPercent(compute_price_impact(
+        &mid_price.0,
+        &input_amount.0,
+        &output_amount.0,
+    )?))
+}
+
+/// Returns the address map with the name of its constant, e.g. `"UNI_ADDRESSES"`.
+fn address_map(name: &str) -> PyResult<AddressMap> {
+    AddressMapKind::ALL
+        .into_iter()
+        .find(|kind| kind.name() == name)
+        .map(AddressMapKind::map)
+        .ok_or_else(|| PyValueError::new_err(format!("unknown address map {name}")))
+}
+
+/// Returns the checksummed address in the address map, or `None` if the chain is missing.
+#[pyfunction]
+fn get_address(map: &str, chain_id: u64) -> PyResult<Option<String>> {
+    Ok(address_map(map)?.get(&chain_id).map(ToString::to_string))
+}
+
+/// Returns the address map as a `dict` of chain IDs to checksummed addresses.
+#[pyfunction]
+fn get_address_map<'py>(py: Python<'py>, map: &str) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    for (chain_id, address) in address_map(map)?.iter() {
+        dict.set_item(chain_id, address.to_string())?;
+    }
+    Ok(dict)
+}
+
+/// The `uniswap_sdk_core` extension module.
+///
+/// Besides the classes and functions, it holds `SUPPORTED_CHAINS`, a `dict` of chain names to
+/// IDs, and `ADDRESS_MAPS`, the names accepted by `get_address` and `get_address_map`.
+#[pymodule]
+#[inline]
+pub fn uniswap_sdk_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyToken>()?;
+    m.add_class::<PyCurrency>()?;
+    m.add_class::<PyFraction>()?;
+    m.add_class::<PyPercent>()?;
+    m.add_class::<PyCurrencyAmount>()?;
+    m.add_class::<PyPrice>()?;
+    m.add_class::<PyRounding>()?;
+    m.add_function(wrap_pyfunction!(py_compute_price_impact, m)?)?;
+    m.add_function(wrap_pyfunction!(get_address, m)?)?;
+    m.add_function(wrap_pyfunction!(get_address_map, m)?)?;
+
+    let chains = PyDict::new(m.py());
+    for chain in SUPPORTED_CHAINS {
+        chains.set_item(format!("{chain:?}"), chain as u64)?;
+    }
+    m.add("SUPPORTED_CHAINS", chains)?;
+    m.add(
+        "ADDRESS_MAPS",
+        AddressMapKind::ALL.map(AddressMapKind::name).to_vec(),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyModule;
+
+    fn run(code: &core::ffi::CStr) {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "uniswap_sdk_core").unwrap();
+            uniswap_sdk_core(&module).unwrap();
+            let globals = PyDict::new(py);
+            globals.set_item("sdk", module).unwrap();
+            py.run(code, Some(&globals), None).unwrap();
+        });
+    }
+
+    #[test]
+    fn test_fraction_operators() {
+        run(cr#"
+Fraction = sdk.Fraction
+a = Fraction(1, 3)
+assert a + a == Fraction(2, 3)
+assert 1 - a == Fraction(2, 3)
+assert a * 3 == 1 and 3 * a == 1
+assert a / Fraction(2) == Fraction(1, 6)
+assert 1 / a == 3
+assert -a < 0 < a
+assert hash(Fraction(2, 6)) == hash(a)
+assert hash(Fraction(6, 3)) == hash(2)
+assert Fraction(2**300, 3).quotient == 2**300 // 3
+assert a.to_significant(3) == "0.333"
+assert a.to_fixed(2, sdk.Rounding.RoundUp) == "0.34"
+try:
+    a / 0
+    raise AssertionError
+except ZeroDivisionError:
+    pass
+"#);
+    }
+
+    #[test]
+    fn test_percent() {
+        run(cr#"
+fee = sdk.Percent.parse("0.3%")
+assert fee == sdk.Percent.from_bps(30) == sdk.Percent(3, 1000)
+assert str(fee) == "0.30%"
+assert (fee + fee).to_fixed(1) == "0.6"
+assert fee * 2 == sdk.Percent(6, 1000)
+assert fee < sdk.Fraction(1, 100)
+"#);
+    }
+
+    #[test]
+    fn test_amounts_and_prices() {
+        run(cr#"
+usdc = sdk.Token(1, "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", 6, "USDC", "USD Coin")
+eth = sdk.Currency.native(1)
+assert eth.wrapped.symbol == "WETH"
+assert usdc.as_currency() == sdk.Currency.token(usdc)
+
+one_eth = sdk.CurrencyAmount.from_raw_amount(eth, 10**18)
+assert (one_eth + one_eth).quotient == 2 * 10**18
+assert (one_eth * sdk.Percent.parse("50%")).to_exact() == "0.5"
+assert one_eth > sdk.CurrencyAmount.from_raw_amount(eth, 1)
+
+price = sdk.Price(eth, usdc.as_currency(), 10**18, 2000 * 10**6)
+assert price.quote(one_eth).to_exact() == "2000"
+assert price.invert().to_significant(1) == "0.0005"
+output = sdk.CurrencyAmount.from_raw_amount(usdc.as_currency(), 1990 * 10**6)
+assert sdk.compute_price_impact(price, one_eth, output) == sdk.Percent(1, 200)
+try:
+    one_eth + output
+    raise AssertionError
+except ValueError as error:
+    assert str(error) == "currency values are not equal"
+"#);
+    }
+
+    #[test]
+    fn test_address_tables() {
+        run(cr#"
+assert sdk.SUPPORTED_CHAINS["MAINNET"] == 1
+assert "UNI_ADDRESSES" in sdk.ADDRESS_MAPS
+assert sdk.get_address("UNI_ADDRESSES", 1) == "0x1f9840a85d5aF5bf1D1762F925BDADdC4201F984"
+assert sdk.get_address("UNI_ADDRESSES", 100) is None
+assert sdk.get_address_map("GOVERNANCE_BRAVO_ADDRESSES") == {
+    1: "0x408ED6354d4973f66138C91495F2f2FCbd8724C3"
+}
+"#);
+    }
+}
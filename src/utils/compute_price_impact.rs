@@ -1,5 +1,17 @@
 use crate::prelude::*;
 
+/// The price impact of a trade split into the part caused by fees and the part caused by moving
+/// the price, both relative to the output quoted by the mid price.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PriceImpact {
+    /// The total price impact, i.e. `fee + slippage`
+    pub total: Percent,
+    /// The part of the price impact paid as fees
+    pub fee: Percent,
+    /// The part of the price impact caused by the trade moving the price
+    pub slippage: Percent,
+}
+
 /// Returns the percent difference between the mid price and the execution price, i.e. price impact.
 ///
 /// # Arguments
@@ -8,14 +20,21 @@ use crate::prelude::*;
 /// * `inputAmount`: the input amount of the trade
 /// * `outputAmount`: the output amount of the trade
 ///
-/// returns: Percent
+/// returns: Percent, or [`Error::ZeroQuote`] if the mid price is zero or has a zero denominator,
+/// or quotes a zero output
 #[inline]
 pub fn compute_price_impact<TBase: BaseCurrency, TQuote: BaseCurrency>(
     mid_price: &Price<TBase, TQuote>,
     input_amount: &CurrencyAmount<TBase>,
     output_amount: &CurrencyAmount<TQuote>,
 ) -> Result<Percent, Error> {
+    if mid_price.numerator == BigInt::ZERO || mid_price.denominator == BigInt::ZERO {
+        return Err(Error::ZeroQuote);
+    }
     let quoted_output_amount = mid_price.quote(input_amount)?;
+    if quoted_output_amount.numerator == BigInt::ZERO {
+        return Err(Error::ZeroQuote);
+    }
     // calculate price impact := (exactQuote - outputAmount) / exactQuote
     let price_impact = quoted_output_amount
         .subtract(output_amount)?
@@ -26,10 +45,112 @@ pub fn compute_price_impact<TBase: BaseCurrency, TQuote: BaseCurrency>(
     ))
 }
 
+/// Chains the mid prices of the hops of a route into the mid price of the whole route.
+///
+/// # Arguments
+///
+/// * `mid_prices`: the mid price of each hop, where each quote currency is the next base currency
+/// * `base_currency`: the input currency of the route
+/// * `quote_currency`: the output currency of the route
+///
+/// returns: the mid price of the route, [`Error::InvalidPath`] if there are no hops, or
+/// [`Error::ZeroQuote`] if a hop has a zero numerator or denominator
+#[inline]
+pub fn chain_mid_prices<TBase: BaseCurrency, TQuote: BaseCurrency>(
+    mid_prices: &[Price<Currency, Currency>],
+    base_currency: TBase,
+    quote_currency: TQuote,
+) -> Result<Price<TBase, TQuote>, Error> {
+    let (first, rest) = mid_prices.split_first().ok_or(Error::InvalidPath)?;
+    if !first.base_currency.equals(&base_currency) {
+        return Err(Error::CurrencyMismatch);
+    }
+    if mid_prices
+        .iter()
+        .any(|price| price.numerator == BigInt::ZERO || price.denominator == BigInt::ZERO)
+    {
+        return Err(Error::ZeroQuote);
+    }
+    let mut price = first.clone();
+    for next in rest {
+        price = price.multiply(next)?;
+    }
+    if !price.quote_currency.equals(&quote_currency) {
+        return Err(Error::CurrencyMismatch);
+    }
+    Ok(Price::new(
+        base_currency,
+        quote_currency,
+        price.denominator,
+        price.numerator,
+    ))
+}
+
+/// Returns the price impact of a multi-hop trade given the mid price of each hop.
+///
+/// # Arguments
+///
+/// * `mid_prices`: the mid price of each hop before the trade, in order
+/// * `input_amount`: the input amount of the trade
+/// * `output_amount`: the output amount of the trade
+///
+/// returns: Percent, [`Error::InvalidPath`] if there are no hops, or [`Error::ZeroQuote`]
+/// if the chained mid price is degenerate
+#[inline]
+pub fn compute_multi_hop_price_impact<TBase: BaseCurrency, TQuote: BaseCurrency>(
+    mid_prices: &[Price<Currency, Currency>],
+    input_amount: &CurrencyAmount<TBase>,
+    output_amount: &CurrencyAmount<TQuote>,
+) -> Result<Percent, Error> {
+    let mid_price = chain_mid_prices(
+        mid_prices,
+        input_amount.currency.clone(),
+        output_amount.currency.clone(),
+    )?;
+    compute_price_impact(&mid_price, input_amount, output_amount)
+}
+
+/// Returns the price impact of a trade split into its fee and slippage components.
+///
+/// # Arguments
+///
+/// * `mid_price`: mid price before the trade
+/// * `input_amount`: the input amount of the trade
+/// * `output_amount`: the output amount of the trade
+/// * `fee`: the share of the input paid as fees, see [`compound_fees`] for multi-hop routes
+#[inline]
+pub fn compute_price_impact_breakdown<TBase: BaseCurrency, TQuote: BaseCurrency>(
+    mid_price: &Price<TBase, TQuote>,
+    input_amount: &CurrencyAmount<TBase>,
+    output_amount: &CurrencyAmount<TQuote>,
+    fee: &Percent,
+) -> Result<PriceImpact, Error> {
+    fee.check_unit("fee")?;
+    let total = compute_price_impact(mid_price, input_amount, output_amount)?;
+    Ok(PriceImpact {
+        slippage: total.clone() - fee.clone(),
+        fee: fee.clone(),
+        total,
+    })
+}
+
+/// Combines the fees charged by each hop of a route into the share of the input paid as fees,
+/// i.e. `1 - (1 - fee_1) * ... * (1 - fee_n)`.
+#[inline]
+#[must_use]
+pub fn compound_fees(fees: &[Percent]) -> Percent {
+    let one = Percent::new(1, 1);
+    one.clone()
+        - fees
+            .iter()
+            .fold(one.clone(), |kept, fee| kept * (one.clone() - fee.clone()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::token;
+    use fastnum::{dec256, D256};
 
     #[test]
     fn test_compute_price_impact() {
@@ -72,4 +193,129 @@ mod tests {
             Percent::new(-10000, 10000)
         )
     }
+
+    #[test]
+    fn test_zero_quote() {
+        let token = token!(1, "0x0000000000000000000000000000000000000001", 18);
+        let token_1 = token!(1, "0x0000000000000000000000000000000000000002", 18);
+        assert_eq!(
+            compute_price_impact(
+                &Price::new(token.clone(), token_1.clone(), 10, 100),
+                &CurrencyAmount::from_raw_amount(token.clone(), 0).unwrap(),
+                &CurrencyAmount::from_raw_amount(token_1.clone(), 0).unwrap()
+            ),
+            Err(Error::ZeroQuote)
+        );
+        assert_eq!(
+            compute_price_impact(
+                &Price::new(token.clone(), token_1.clone(), 10, 0),
+                &CurrencyAmount::from_raw_amount(token.clone(), 10).unwrap(),
+                &CurrencyAmount::from_raw_amount(token_1.clone(), 0).unwrap()
+            ),
+            Err(Error::ZeroQuote)
+        );
+        // the fields are public, so a zero denominator can only be set directly
+        let mut degenerate = Price::new(token.clone(), token_1.clone(), 10, 10);
+        degenerate.denominator = BigInt::ZERO;
+        assert_eq!(
+            compute_price_impact(
+                &degenerate,
+                &CurrencyAmount::from_raw_amount(token.clone(), 10).unwrap(),
+                &CurrencyAmount::from_raw_amount(token_1.clone(), 10).unwrap()
+            ),
+            Err(Error::ZeroQuote)
+        );
+        let mut degenerate: Price<Currency, Currency> =
+            Price::new(token.clone().into(), token_1.clone().into(), 10, 10);
+        degenerate.denominator = BigInt::ZERO;
+        assert_eq!(
+            compute_multi_hop_price_impact(
+                &[degenerate],
+                &CurrencyAmount::from_raw_amount(token.clone(), 10).unwrap(),
+                &CurrencyAmount::from_raw_amount(token_1.clone(), 10).unwrap()
+            ),
+            Err(Error::ZeroQuote)
+        );
+        assert_eq!(
+            compute_multi_hop_price_impact(
+                &[],
+                &CurrencyAmount::from_raw_amount(token, 10).unwrap(),
+                &CurrencyAmount::from_raw_amount(token_1, 10).unwrap()
+            ),
+            Err(Error::InvalidPath)
+        );
+    }
+
+    #[test]
+    fn test_multi_hop() {
+        let token: Currency = token!(1, "0x0000000000000000000000000000000000000001", 18).into();
+        let token_1: Currency = token!(1, "0x0000000000000000000000000000000000000002", 18).into();
+        let token_2: Currency = token!(1, "0x0000000000000000000000000000000000000003", 18).into();
+        let mid_prices = [
+            Price::new(token.clone(), token_1.clone(), 10, 20),
+            Price::new(token_1, token_2.clone(), 10, 30),
+        ];
+        let input = CurrencyAmount::from_raw_amount(token, 10).unwrap();
+
+        // the route quotes 60
+        assert_eq!(
+            compute_multi_hop_price_impact(
+                &mid_prices,
+                &input,
+                &CurrencyAmount::from_raw_amount(token_2.clone(), 45).unwrap()
+            )
+            .unwrap(),
+            Percent::new(15, 60)
+        );
+        assert_eq!(
+            compute_multi_hop_price_impact(
+                &mid_prices[..1],
+                &input,
+                &CurrencyAmount::from_raw_amount(token_2.clone(), 45).unwrap()
+            ),
+            Err(Error::CurrencyMismatch)
+        );
+        assert_eq!(
+            compute_multi_hop_price_impact(
+                &[mid_prices[1].clone(), mid_prices[0].clone()],
+                &input,
+                &CurrencyAmount::from_raw_amount(token_2, 45).unwrap()
+            ),
+            Err(Error::CurrencyMismatch)
+        );
+    }
+
+    #[test]
+    fn test_breakdown() {
+        let token = token!(1, "0x0000000000000000000000000000000000000001", 18);
+        let token_1 = token!(1, "0x0000000000000000000000000000000000000002", 18);
+        let fee = compound_fees(&[Percent::new(3, 1000), Percent::new(5, 10000)]);
+        assert_eq!(fee, Percent::new(3_4985, 10_000_000));
+
+        let impact = compute_price_impact_breakdown(
+            &Price::new(token.clone(), token_1.clone(), 1, 1),
+            &CurrencyAmount::from_raw_amount(token.clone(), 10_000_000).unwrap(),
+            &CurrencyAmount::from_raw_amount(token_1.clone(), 9_900_000).unwrap(),
+            &fee,
+        )
+        .unwrap();
+        assert_eq!(impact.total, Percent::new(1, 100));
+        assert_eq!(impact.fee, fee);
+        assert_eq!(impact.slippage, Percent::new(65015, 10_000_000));
+        assert_eq!(impact.fee.clone() + impact.slippage, impact.total);
+
+        assert_eq!(
+            compute_price_impact_breakdown(
+                &Price::new(token.clone(), token_1.clone(), 1, 1),
+                &CurrencyAmount::from_raw_amount(token, 1).unwrap(),
+                &CurrencyAmount::from_raw_amount(token_1, 1).unwrap(),
+                &Percent::new(101, 100),
+            ),
+            Err(Error::OutOfRange {
+                what: "fee",
+                value: dec256!(1.01),
+                max: D256::ONE,
+            })
+        );
+    }
 }
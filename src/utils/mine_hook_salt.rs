@@ -0,0 +1,253 @@
+use alloy_primitives::{Address, B256, U256};
+
+/// Mask of the lowest 14 bits of a hook address, which encode the hook permissions.
+pub const ALL_HOOK_MASK: u16 = (1 << 14) - 1;
+
+/// The hook functions a Uniswap v4 hook implements, as encoded in the lowest 14 bits of its
+/// address.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct HookPermissions {
+    pub before_initialize: bool,
+    pub after_initialize: bool,
+    pub before_add_liquidity: bool,
+    pub after_add_liquidity: bool,
+    pub before_remove_liquidity: bool,
+    pub after_remove_liquidity: bool,
+    pub before_swap: bool,
+    pub after_swap: bool,
+    pub before_donate: bool,
+    pub after_donate: bool,
+    pub before_swap_returns_delta: bool,
+    pub after_swap_returns_delta: bool,
+    pub after_add_liquidity_returns_delta: bool,
+    pub after_remove_liquidity_returns_delta: bool,
+}
+
+impl HookPermissions {
+    /// Decodes the permissions from the flag bits of a hook address.
+    #[inline]
+    #[must_use]
+    pub const fn from_flags(flags: u16) -> Self {
+        Self {
+            before_initialize: flags & (1 << 13) != 0,
+            after_initialize: flags & (1 << 12) != 0,
+            before_add_liquidity: flags & (1 << 11) != 0,
+            after_add_liquidity: flags & (1 << 10) != 0,
+            before_remove_liquidity: flags & (1 << 9) != 0,
+            after_remove_liquidity: flags & (1 << 8) != 0,
+            before_swap: flags & (1 << 7) != 0,
+            after_swap: flags & (1 << 6) != 0,
+            before_donate: flags & (1 << 5) != 0,
+            after_donate: flags & (1 << 4) != 0,
+            before_swap_returns_delta: flags & (1 << 3) != 0,
+            after_swap_returns_delta: flags & (1 << 2) != 0,
+            after_add_liquidity_returns_delta: flags & (1 << 1) != 0,
+            after_remove_liquidity_returns_delta: flags & 1 != 0,
+        }
+    }
+
+    /// Encodes the permissions into the flag bits a hook address must carry.
+    #[inline]
+    #[must_use]
+    pub const fn to_flags(self) -> u16 {
+        (self.before_initialize as u16) << 13
+            | (self.after_initialize as u16) << 12
+            | (self.before_add_liquidity as u16) << 11
+            | (self.after_add_liquidity as u16) << 10
+            | (self.before_remove_liquidity as u16) << 9
+            | (self.after_remove_liquidity as u16) << 8
+            | (self.before_swap as u16) << 7
+            | (self.after_swap as u16) << 6
+            | (self.before_donate as u16) << 5
+            | (self.after_donate as u16) << 4
+            | (self.before_swap_returns_delta as u16) << 3
+            | (self.after_swap_returns_delta as u16) << 2
+            | (self.after_add_liquidity_returns_delta as u16) << 1
+            | self.after_remove_liquidity_returns_delta as u16
+    }
+
+    /// Returns the permissions encoded in a hook address.
+    #[inline]
+    #[must_use]
+    pub const fn from_address(address: Address) -> Self {
+        Self::from_flags(hook_flags(address))
+    }
+}
+
+/// Returns the flag bits of a hook address.
+#[inline]
+const fn hook_flags(address: Address) -> u16 {
+    u16::from_be_bytes([address.0 .0[18], address.0 .0[19]]) & ALL_HOOK_MASK
+}
+
+/// Computes the salt at `offset` from `start_salt` and the hook address it deploys to, if the
+/// address carries exactly `flags`.
+#[inline]
+fn try_salt(
+    deployer: Address,
+    init_code_hash: B256,
+    flags: u16,
+    start_salt: U256,
+    offset: u64,
+) -> Option<(B256, Address)> {
+    let salt = B256::from(
+        start_salt
+            .wrapping_add(U256::from(offset))
+            .to_be_bytes::<32>(),
+    );
+    let address = deployer.create2(salt, init_code_hash);
+    (hook_flags(address) == flags).then_some((salt, address))
+}
+
+/// Searches for a CREATE2 salt such that the hook deployed by `deployer` has exactly the requested
+/// permissions encoded in its address.
+///
+/// Salts are tried sequentially from `start_salt`. Note that unlike the Solidity `HookMiner`, this
+/// does not check that the address is free on chain.
+///
+/// # Arguments
+///
+/// * `deployer`: the address of the CREATE2 deployer
+/// * `init_code_hash`: the keccak256 hash of the hook creation code including constructor args
+/// * `permissions`: the permissions the hook address must encode
+/// * `start_salt`: the first salt to try
+/// * `max_iterations`: the maximum number of salts to try
+///
+/// returns: `Some((salt, hook_address))` for the first matching salt, `None` if none was found
+#[inline]
+#[must_use]
+pub fn mine_hook_salt(
+    deployer: Address,
+    init_code_hash: B256,
+    permissions: HookPermissions,
+    start_salt: U256,
+    max_iterations: u64,
+) -> Option<(B256, Address)> {
+    let flags = permissions.to_flags();
+    (0..max_iterations)
+        .find_map(|offset| try_salt(deployer, init_code_hash, flags, start_salt, offset))
+}
+
+/// Multi-threaded version of [`mine_hook_salt`] using all available cores.
+///
+/// Returns the same salt as [`mine_hook_salt`], i.e. the first matching one from `start_salt`.
+#[cfg(feature = "std")]
+#[inline]
+#[must_use]
+pub fn mine_hook_salt_parallel(
+    deployer: Address,
+    init_code_hash: B256,
+    permissions: HookPermissions,
+    start_salt: U256,
+    max_iterations: u64,
+) -> Option<(B256, Address)> {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    let flags = permissions.to_flags();
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get()) as u64;
+    // the lowest matching offset found so far, shared so that threads can stop early
+    let found = AtomicU64::new(u64::MAX);
+    std::thread::scope(|scope| {
+        for thread in 0..threads {
+            let found = &found;
+            scope.spawn(move || {
+                let mut offset = thread;
+                while offset < max_iterations && offset < found.load(Ordering::Relaxed) {
+                    if try_salt(deployer, init_code_hash, flags, start_salt, offset).is_some() {
+                        found.fetch_min(offset, Ordering::Relaxed);
+                        return;
+                    }
+                    let Some(next) = offset.checked_add(threads) else {
+                        return;
+                    };
+                    offset = next;
+                }
+            });
+        }
+    });
+    match found.into_inner() {
+        u64::MAX => None,
+        offset => try_salt(deployer, init_code_hash, flags, start_salt, offset),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256};
+
+    const DEPLOYER: Address = address!("4e59b44847b379578588920cA78FbF26c0B4956C");
+    const INIT_CODE_HASH: B256 =
+        b256!("94d114296a5af85c1fd2dc039cdaa32f1ed4b0fe0868f02d888bfc91feb645d9");
+
+    #[test]
+    fn test_flags_round_trip() {
+        for flags in [0, 1, 0x00c0, 0x2aaa, ALL_HOOK_MASK] {
+            assert_eq!(HookPermissions::from_flags(flags).to_flags(), flags);
+        }
+        let permissions = HookPermissions {
+            before_swap: true,
+            after_swap: true,
+            ..Default::default()
+        };
+        assert_eq!(permissions.to_flags(), 0x00c0);
+    }
+
+    #[test]
+    fn test_from_address() {
+        let permissions =
+            HookPermissions::from_address(address!("00000000000000000000000000000000000020c0"));
+        assert!(permissions.before_initialize);
+        assert!(permissions.before_swap);
+        assert!(permissions.after_swap);
+        assert!(!permissions.after_initialize);
+    }
+
+    #[test]
+    fn test_mine_hook_salt() {
+        let permissions = HookPermissions {
+            before_swap: true,
+            after_swap: true,
+            ..Default::default()
+        };
+        let (salt, hook) =
+            mine_hook_salt(DEPLOYER, INIT_CODE_HASH, permissions, U256::ZERO, 1 << 20).unwrap();
+        assert_eq!(hook, DEPLOYER.create2(salt, INIT_CODE_HASH));
+        assert_eq!(HookPermissions::from_address(hook), permissions);
+
+        // starting past the found salt yields a different one
+        let (next_salt, _) = mine_hook_salt(
+            DEPLOYER,
+            INIT_CODE_HASH,
+            permissions,
+            U256::from_be_bytes(salt.0) + U256::from(1),
+            1 << 20,
+        )
+        .unwrap();
+        assert!(U256::from_be_bytes(next_salt.0) > U256::from_be_bytes(salt.0));
+    }
+
+    #[test]
+    fn test_mine_hook_salt_exhausted() {
+        assert_eq!(
+            mine_hook_salt(
+                DEPLOYER,
+                INIT_CODE_HASH,
+                HookPermissions::from_flags(ALL_HOOK_MASK),
+                U256::ZERO,
+                16
+            ),
+            None
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_mine_hook_salt_parallel() {
+        let permissions = HookPermissions::from_flags(0x2080);
+        assert_eq!(
+            mine_hook_salt_parallel(DEPLOYER, INIT_CODE_HASH, permissions, U256::ZERO, 1 << 20),
+            mine_hook_salt(DEPLOYER, INIT_CODE_HASH, permissions, U256::ZERO, 1 << 20)
+        );
+    }
+}
@@ -1,14 +1,17 @@
 pub mod compute_price_impact;
 pub mod compute_zksync_create2_address;
+pub mod mine_hook_salt;
+pub mod parse_big_int;
 pub mod sorted_insert;
 pub mod sqrt;
 mod types;
+pub mod validate_and_parse_address;
 
-pub use compute_price_impact::compute_price_impact;
+pub use compute_price_impact::*;
 pub use compute_zksync_create2_address::compute_zksync_create2_address;
+pub use mine_hook_salt::*;
+pub use parse_big_int::parse_big_int;
 pub use sorted_insert::sorted_insert;
 pub use sqrt::sqrt;
 pub use types::*;
-
-#[cfg(feature = "validate_parse_address")]
-pub mod validate_and_parse_address;
+pub use validate_and_parse_address::parse_checksummed_address;
@@ -0,0 +1,54 @@
+use crate::prelude::*;
+
+/// Parses a decimal integer such as `"-42"`.
+///
+/// # Arguments
+///
+/// * `value`: the decimal digits, with an optional sign
+///
+/// returns: BigInt, or [`Error::Parse`] at the first character that is not a digit, or at the
+/// first digit if the integer does not fit
+#[inline]
+pub fn parse_big_int(value: &str) -> Result<BigInt, Error> {
+    BigInt::from_str_radix(value, 10).map_err(|_| {
+        let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+        let offset = value.len() - digits.len();
+        let position = match digits.bytes().position(|b| !b.is_ascii_digit()) {
+            Some(position) => offset + position,
+            None if digits.is_empty() => value.len(),
+            None => offset,
+        };
+        Error::Parse {
+            what: "integer",
+            position,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_big_int() {
+        assert_eq!(parse_big_int("42"), Ok(BigInt::from(42)));
+        assert_eq!(parse_big_int("-42"), Ok(BigInt::from(-42)));
+        for (invalid, position) in [("", 0), ("-", 1), ("1.5", 1), ("-0x10", 2), (" 1", 0)] {
+            assert_eq!(
+                parse_big_int(invalid),
+                Err(Error::Parse {
+                    what: "integer",
+                    position
+                })
+            );
+        }
+        let too_large = "9".repeat(200);
+        assert_eq!(
+            parse_big_int(&too_large),
+            Err(Error::Parse {
+                what: "integer",
+                position: 0
+            })
+        );
+    }
+}
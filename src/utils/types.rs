@@ -1,6 +1,10 @@
 use crate::prelude::{BigDecimal, BigInt, BigUint};
 use alloy_primitives::{Signed, Uint};
-use fastnum::decimal::{Context, Sign};
+use fastnum::{
+    bint::{Int, UInt},
+    decimal::{Context, Sign},
+};
+use num_traits::AsPrimitive;
 
 pub trait ToBig: Sized {
     fn to_big_uint(self) -> BigUint;
@@ -89,6 +93,25 @@ impl<const BITS: usize, const LIMBS: usize> ToBig for Signed<BITS, LIMBS> {
     }
 }
 
+/// Converts between integer widths, wrapping on overflow like `as` casts.
+pub(crate) trait CastFrom<T> {
+    fn cast_from(from: T) -> Self;
+}
+
+impl<const N: usize, const M: usize> CastFrom<Int<M>> for Int<N> {
+    #[inline]
+    fn cast_from(from: Int<M>) -> Self {
+        from.as_()
+    }
+}
+
+impl<const N: usize> CastFrom<u64> for Int<N> {
+    #[inline]
+    fn cast_from(from: u64) -> Self {
+        Self::from_bits(UInt::from_u64(from))
+    }
+}
+
 pub trait FromBig: Sized {
     fn from_big_uint(x: BigUint) -> Self;
 
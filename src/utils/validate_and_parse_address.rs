@@ -1,3 +1,5 @@
+use crate::prelude::*;
+#[cfg(feature = "validate_parse_address")]
 use regex::Regex;
 
 /// Checks if the input string is a valid Ethereum address.
@@ -9,20 +11,23 @@ use regex::Regex;
 /// # Returns
 ///
 /// * If the input string satisfies the condition of starting with `0x` and being 42 characters long
-///   with only hexadecimal characters after `0x`, returns `Ok(ethereum_address.to_string())`.
-/// * Otherwise, returns an error message in the form of `Err(format!("{} is not a valid Ethereum
-///   address.", ethereum_address))`.
+///   with only hexadecimal characters after `0x`, returns `Ok(ethereum_address)`.
+/// * Otherwise, returns [`Error::Parse`] at the position of the first invalid character.
+#[cfg(feature = "validate_parse_address")]
 #[inline]
-pub fn check_valid_ethereum_address(ethereum_address: &str) -> Result<&str, String> {
+pub fn check_valid_ethereum_address(ethereum_address: &str) -> Result<&str, Error> {
     let valid_address_regex = Regex::new(r"^0x[0-9a-fA-F]{40}$").unwrap();
     if valid_address_regex.is_match(ethereum_address) {
-        Ok(ethereum_address)
-    } else {
-        Err(format!(
-            "{} is not a valid Ethereum address.",
-            ethereum_address
-        ))
+        return Ok(ethereum_address);
     }
+    let position = match ethereum_address.strip_prefix("0x") {
+        Some(hex) => 2 + invalid_hex_position(hex).unwrap_or_default(),
+        None => usize::from(ethereum_address.starts_with('0')),
+    };
+    Err(Error::Parse {
+        what: "address",
+        position,
+    })
 }
 
 /// Validates the input string as an Ethereum address and returns the checksummed address.
@@ -30,38 +35,121 @@ pub fn check_valid_ethereum_address(ethereum_address: &str) -> Result<&str, Stri
 /// # Arguments
 ///
 /// * `ethereum_address` - A string slice that holds the Ethereum address to be validated and
-///   checksummed.
+///   checksummed, with or without the `0x` prefix.
 ///
 /// # Returns
 ///
-/// * If the input string satisfies the condition of starting with `0x` and being 42 characters long
-///   with only hexadecimal characters after `0x`, returns the checksummed address.
-/// * Otherwise, returns an error message in the form of `Err(format!("{} is not a valid Ethereum
-///   address.", ethereum_address))`.
+/// * If the input string consists of 40 hexadecimal characters after an optional `0x`, returns the
+///   checksummed address.
+/// * Otherwise, returns [`Error::Parse`] at the position of the first invalid character.
+#[cfg(feature = "validate_parse_address")]
+#[inline]
+pub fn validate_and_parse_address(ethereum_address: &str) -> Result<String, Error> {
+    let (_, address) = parse_hex(ethereum_address).map_err(|position| Error::Parse {
+        what: "address",
+        position,
+    })?;
+    Ok(address.to_checksum(None))
+}
+
+/// Parses an address, with or without the `0x` prefix, verifying the EIP-55 checksum if it is
+/// mixed-case. All lowercase or all uppercase addresses are accepted as is.
+///
+/// # Returns
+///
+/// * [`Error::InvalidAddress`] if the input is not 40 hexadecimal characters after an optional
+///   `0x`.
+/// * [`Error::ChecksumMismatch`] if a mixed-case address does not match its checksum.
+#[inline]
+pub fn parse_checksummed_address(address: &str) -> Result<Address, Error> {
+    let (hex, parsed) = parse_hex(address).map_err(|_| Error::InvalidAddress)?;
+    let is_mixed_case =
+        hex.bytes().any(|b| b.is_ascii_lowercase()) && hex.bytes().any(|b| b.is_ascii_uppercase());
+    if is_mixed_case && parsed.to_checksum(None)[2..] != *hex {
+        return Err(Error::ChecksumMismatch(parsed));
+    }
+    Ok(parsed)
+}
+
+/// Parses 40 hexadecimal characters after an optional `0x`, returning them with the address, or
+/// the position of the first invalid character.
 #[inline]
-pub fn validate_and_parse_address(ethereum_address: &str) -> Result<String, String> {
-    let checksummed_address = eth_checksum::checksum(ethereum_address);
-    check_valid_ethereum_address(&checksummed_address)?;
-    Ok(checksummed_address)
+fn parse_hex(address: &str) -> Result<(&str, Address), usize> {
+    let hex = address.strip_prefix("0x").unwrap_or(address);
+    let prefix = address.len() - hex.len();
+    match invalid_hex_position(hex) {
+        Some(position) => Err(prefix + position),
+        None => Ok((hex, hex.parse().map_err(|_| prefix)?)),
+    }
+}
+
+/// Returns the position of the first character that does not continue 40 hexadecimal characters.
+#[inline]
+fn invalid_hex_position(hex: &str) -> Option<usize> {
+    match hex.bytes().position(|b| !b.is_ascii_hexdigit()) {
+        Some(position) if position < 40 => Some(position),
+        _ if hex.len() == 40 => None,
+        _ => Some(hex.len().min(40)),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_primitives::address;
+
+    #[test]
+    fn test_parse_checksummed_address() {
+        const DAI: Address = address!("0x6B175474E89094C44Da98b954EedeAC495271d0F");
+        assert_eq!(
+            parse_checksummed_address("0x6B175474E89094C44Da98b954EedeAC495271d0F"),
+            Ok(DAI)
+        );
+        assert_eq!(
+            parse_checksummed_address("6b175474e89094c44da98b954eedeac495271d0f"),
+            Ok(DAI)
+        );
+        assert_eq!(
+            parse_checksummed_address("0x6b175474E89094C44Da98b954EedeAC495271d0F"),
+            Err(Error::ChecksumMismatch(DAI))
+        );
+        assert_eq!(
+            parse_checksummed_address("0x1234"),
+            Err(Error::InvalidAddress)
+        );
+    }
 
     #[test]
+    #[cfg(feature = "validate_parse_address")]
     fn test_valid_ethereum_address() {
         let valid_address = "0x1234567890123456789012345678901234567890";
         assert!(check_valid_ethereum_address(valid_address).is_ok());
     }
 
     #[test]
+    #[cfg(feature = "validate_parse_address")]
     fn test_invalid_ethereum_address() {
-        let invalid_address = "0xinvalidaddress";
-        assert!(check_valid_ethereum_address(invalid_address).is_err());
+        let parse_error = |position| Error::Parse {
+            what: "address",
+            position,
+        };
+        assert_eq!(
+            check_valid_ethereum_address("0xinvalidaddress"),
+            Err(parse_error(2))
+        );
+        assert_eq!(
+            check_valid_ethereum_address("1234567890123456789012345678901234567890"),
+            Err(parse_error(0))
+        );
+        assert_eq!(check_valid_ethereum_address("0x1234"), Err(parse_error(6)));
+        assert_eq!(
+            check_valid_ethereum_address("0x12345678901234567890123456789012345678901"),
+            Err(parse_error(42))
+        );
     }
 
     #[test]
+    #[cfg(feature = "validate_parse_address")]
     fn test_validate_and_parse_address() {
         let valid_address = "0x1234567890123456789012345678901234567890";
 
@@ -69,14 +157,18 @@ mod tests {
             validate_and_parse_address(valid_address),
             Ok(valid_address.to_string())
         );
+        assert_eq!(
+            validate_and_parse_address(&valid_address[2..]),
+            Ok(valid_address.to_string())
+        );
 
         let invalid_address = "0xInvAlIdAddrEsS";
         assert_eq!(
             validate_and_parse_address(invalid_address),
-            Err(format!(
-                "{} is not a valid Ethereum address.",
-                invalid_address
-            ))
+            Err(Error::Parse {
+                what: "address",
+                position: 2
+            })
         );
     }
 }
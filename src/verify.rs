@@ -0,0 +1,379 @@
+use crate::prelude::*;
+use core::fmt;
+
+/// The address tables checked by [`Tables::verify`].
+#[derive(Clone, Copy, Debug)]
+pub struct Tables {
+    /// The deployments of each chain, like [`CHAIN_TO_ADDRESSES_MAP`].
+    pub deployments: ChainMap<ChainAddresses>,
+    /// The v2 factories, like [`V2_FACTORY_ADDRESSES`].
+    pub v2_factory: AddressMap,
+    /// The v2 routers, like [`V2_ROUTER_ADDRESSES`].
+    pub v2_router: AddressMap,
+    /// The wrapped native tokens, like [`WRAPPED_NATIVE_TOKENS`].
+    pub wrapped_native: ChainMap<WrappedNative>,
+    /// The UNI tokens, like [`UNI_ADDRESSES`].
+    pub uni: AddressMap,
+}
+
+/// An entry of a table, identified by the table, the contract and the chain.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Entry {
+    /// The name of the table, e.g. `"CHAIN_TO_ADDRESSES_MAP"`.
+    pub table: &'static str,
+    /// The contract, e.g. `"quoter_v2"` or `"v2_router"`.
+    pub contract: &'static str,
+    /// The chain ID of the entry.
+    pub chain_id: u64,
+}
+
+/// A suspicious entry of the address tables.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Finding {
+    /// The entry is the zero address.
+    ZeroAddress(Entry),
+    /// The same address is listed for two different contracts on the same chain.
+    DuplicateAddress {
+        address: Address,
+        first: Entry,
+        second: Entry,
+    },
+    /// A testnet has the same deployments as a mainnet, which is usually a copy-paste error.
+    TestnetSharesMainnet { testnet: u64, mainnet: u64 },
+    /// A chain is in one table but missing from another one that should cover it.
+    MissingChain {
+        chain_id: u64,
+        present_in: &'static str,
+        missing_from: &'static str,
+    },
+}
+
+impl fmt::Display for Entry {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{} on chain {}",
+            self.table, self.contract, self.chain_id
+        )
+    }
+}
+
+impl fmt::Display for Finding {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroAddress(entry) => write!(f, "{entry} is the zero address"),
+            Self::DuplicateAddress {
+                address,
+                first,
+                second,
+            } => write!(f, "{first} and {second} are both {address}"),
+            Self::TestnetSharesMainnet { testnet, mainnet } => write!(
+                f,
+                "testnet {testnet} has the same deployments as mainnet {mainnet}"
+            ),
+            Self::MissingChain {
+                chain_id,
+                present_in,
+                missing_from,
+            } => write!(
+                f,
+                "chain {chain_id} is in {present_in} but missing from {missing_from}"
+            ),
+        }
+    }
+}
+
+/// The findings of [`Tables::verify`], in the order of the checks.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    /// The suspicious entries.
+    pub findings: Vec<Finding>,
+}
+
+impl Report {
+    /// Returns whether there are no findings.
+    #[inline]
+    #[must_use]
+    pub const fn is_clean(&self) -> bool {
+        self.findings.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    /// Writes one finding per line.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for finding in &self.findings {
+            writeln!(f, "{finding}")?;
+        }
+        Ok(())
+    }
+}
+
+const DEPLOYMENTS: &str = "CHAIN_TO_ADDRESSES_MAP";
+const V2_FACTORY: &str = "V2_FACTORY_ADDRESSES";
+const V2_ROUTER: &str = "V2_ROUTER_ADDRESSES";
+const WRAPPED_NATIVE: &str = "WRAPPED_NATIVE_TOKENS";
+const UNI: &str = "UNI_ADDRESSES";
+
+impl Tables {
+    /// The tables of the SDK.
+    pub const SDK: Self = Self {
+        deployments: CHAIN_TO_ADDRESSES_MAP,
+        v2_factory: V2_FACTORY_ADDRESSES,
+        v2_router: V2_ROUTER_ADDRESSES,
+        wrapped_native: WRAPPED_NATIVE_TOKENS,
+        uni: UNI_ADDRESSES,
+    };
+
+    /// Returns every address of the tables with its entry.
+    #[inline]
+    fn entries(&self) -> Vec<(Entry, Address)> {
+        let entry = |table, contract, chain_id: &u64| Entry {
+            table,
+            contract,
+            chain_id: *chain_id,
+        };
+        let mut entries = Vec::new();
+        for (chain_id, addresses) in self.deployments.iter() {
+            for (contract, address) in ChainAddresses::CONTRACT_NAMES
+                .into_iter()
+                .zip(addresses.contracts())
+            {
+                if let Some(address) = address {
+                    entries.push((entry(DEPLOYMENTS, contract, chain_id), address));
+                }
+            }
+        }
+        for (table, contract, map) in [
+            (V2_FACTORY, "v2_factory", self.v2_factory),
+            (V2_ROUTER, "v2_router", self.v2_router),
+            (UNI, "uni", self.uni),
+        ] {
+            for (chain_id, address) in map.iter() {
+                entries.push((entry(table, contract, chain_id), *address));
+            }
+        }
+        for (chain_id, wrapped) in self.wrapped_native.iter() {
+            entries.push((
+                entry(WRAPPED_NATIVE, "wrapped_native", chain_id),
+                wrapped.address,
+            ));
+        }
+        entries
+    }
+
+    /// Checks the tables for zero addresses, addresses listed for different contracts on the same
+    /// chain, testnets that share the deployments of a mainnet and chains missing from a table.
+    ///
+    /// Addresses are only compared on the same chain, since a deployer reaching the same nonce on
+    /// two chains legitimately deploys different contracts at the same address. The chains of
+    /// [`Tables::deployments`] should have a wrapped native token, and the chains of
+    /// [`Tables::v2_factory`] and [`Tables::v2_router`] should be the same. Chain IDs that are not
+    /// a [`ChainId`] are not known to be testnets.
+    #[inline]
+    #[must_use]
+    pub fn verify(&self) -> Report {
+        let mut findings = Vec::new();
+        let entries = self.entries();
+
+        for (entry, address) in &entries {
+            if address.is_zero() {
+                findings.push(Finding::ZeroAddress(*entry));
+            }
+        }
+
+        for (i, (first, address)) in entries.iter().enumerate() {
+            for (second, _) in entries[i + 1..].iter().filter(|(second, other)| {
+                other == address
+                    && second.chain_id == first.chain_id
+                    && second.contract != first.contract
+                    && !is_alias(first.contract, second.contract)
+            }) {
+                findings.push(Finding::DuplicateAddress {
+                    address: *address,
+                    first: *first,
+                    second: *second,
+                });
+            }
+        }
+
+        let is_testnet =
+            |chain_id: &u64| ChainId::from_id(*chain_id).is_some_and(ChainId::is_testnet);
+        let is_mainnet =
+            |chain_id: &u64| ChainId::from_id(*chain_id).is_some_and(|c| !c.is_testnet());
+        for (testnet, addresses) in self.deployments.iter().filter(|(id, _)| is_testnet(id)) {
+            for (mainnet, _) in self
+                .deployments
+                .iter()
+                .filter(|(id, other)| is_mainnet(id) && *other == addresses)
+            {
+                findings.push(Finding::TestnetSharesMainnet {
+                    testnet: *testnet,
+                    mainnet: *mainnet,
+                });
+            }
+        }
+
+        missing_chains(
+            &mut findings,
+            (DEPLOYMENTS, self.deployments.keys()),
+            (WRAPPED_NATIVE, |chain_id| {
+                self.wrapped_native.contains_key(chain_id)
+            }),
+        );
+        missing_chains(
+            &mut findings,
+            (V2_FACTORY, self.v2_factory.keys()),
+            (V2_ROUTER, |chain_id| self.v2_router.contains_key(chain_id)),
+        );
+        missing_chains(
+            &mut findings,
+            (V2_ROUTER, self.v2_router.keys()),
+            (V2_FACTORY, |chain_id| {
+                self.v2_factory.contains_key(chain_id)
+            }),
+        );
+
+        Report { findings }
+    }
+}
+
+/// Returns whether the contracts may share an address, since chains that only have a QuoterV2 list
+/// it as their quoter too.
+#[inline]
+fn is_alias(first: &str, second: &str) -> bool {
+    matches!(
+        (first, second),
+        ("quoter", "quoter_v2") | ("quoter_v2", "quoter")
+    )
+}
+
+/// Reports the chains of one table that are missing from another.
+#[inline]
+fn missing_chains<'a>(
+    findings: &mut Vec<Finding>,
+    (present_in, chain_ids): (&'static str, impl Iterator<Item = &'a u64>),
+    (missing_from, contains): (&'static str, impl Fn(&u64) -> bool),
+) {
+    for chain_id in chain_ids.filter(|chain_id| !contains(chain_id)) {
+        findings.push(Finding::MissingChain {
+            chain_id: *chain_id,
+            present_in,
+            missing_from,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec};
+
+    const MAINNET: u64 = ChainId::MAINNET as u64;
+    const SEPOLIA: u64 = ChainId::SEPOLIA as u64;
+    const MAINNET_ADDRESSES: ChainAddresses = CHAIN_TO_ADDRESSES_MAP.entries()[0].1;
+    const MAINNET_V3_FACTORY: Address = V3_CORE_FACTORY_ADDRESSES.entries()[0].1;
+
+    /// Tables with one bug of every kind.
+    const FIXTURE: Tables = Tables {
+        // Sepolia copy-pasted from mainnet
+        deployments: ChainMap::new(&[(MAINNET, MAINNET_ADDRESSES), (SEPOLIA, MAINNET_ADDRESSES)]),
+        // a zero address, and optimism without a router
+        v2_factory: AddressMap::new(&[(MAINNET, Address::ZERO), (10, V2_FACTORY_ADDRESS)]),
+        // the v3 factory pasted as the router
+        v2_router: AddressMap::new(&[(MAINNET, MAINNET_V3_FACTORY)]),
+        // no wrapped native token on Sepolia
+        wrapped_native: ChainMap::new(WRAPPED_NATIVE_TOKENS.entries().split_at(1).0),
+        uni: AddressMap::new(&[]),
+    };
+
+    #[test]
+    fn test_fixture() {
+        let report = FIXTURE.verify();
+        assert_eq!(
+            report.findings,
+            [
+                Finding::ZeroAddress(Entry {
+                    table: V2_FACTORY,
+                    contract: "v2_factory",
+                    chain_id: MAINNET,
+                }),
+                Finding::DuplicateAddress {
+                    address: MAINNET_V3_FACTORY,
+                    first: Entry {
+                        table: DEPLOYMENTS,
+                        contract: "v3_core_factory",
+                        chain_id: MAINNET,
+                    },
+                    second: Entry {
+                        table: V2_ROUTER,
+                        contract: "v2_router",
+                        chain_id: MAINNET,
+                    },
+                },
+                Finding::TestnetSharesMainnet {
+                    testnet: SEPOLIA,
+                    mainnet: MAINNET,
+                },
+                Finding::MissingChain {
+                    chain_id: SEPOLIA,
+                    present_in: DEPLOYMENTS,
+                    missing_from: WRAPPED_NATIVE,
+                },
+                Finding::MissingChain {
+                    chain_id: 10,
+                    present_in: V2_FACTORY,
+                    missing_from: V2_ROUTER,
+                },
+            ]
+        );
+        assert!(!report.is_clean());
+        assert_eq!(
+            report.findings[0].to_string(),
+            "V2_FACTORY_ADDRESSES.v2_factory on chain 1 is the zero address"
+        );
+    }
+
+    #[test]
+    fn test_sdk_tables() {
+        let report = Tables::SDK.verify();
+        // known issues, which a change to the tables should only ever remove
+        let mut known = vec![
+            Finding::TestnetSharesMainnet {
+                testnet: ChainId::POLYGON_MUMBAI as u64,
+                mainnet: ChainId::POLYGON as u64,
+            },
+            Finding::TestnetSharesMainnet {
+                testnet: ChainId::CELO_ALFAJORES as u64,
+                mainnet: ChainId::CELO as u64,
+            },
+        ];
+        for chain_id in [
+            ChainId::POLYGON_MUMBAI,
+            ChainId::CELO,
+            ChainId::CELO_ALFAJORES,
+            ChainId::OPTIMISM_GOERLI,
+            ChainId::ARBITRUM_GOERLI,
+            ChainId::BASE_GOERLI,
+            ChainId::ZORA_SEPOLIA,
+            ChainId::ROOTSTOCK,
+        ] {
+            known.push(Finding::MissingChain {
+                chain_id: chain_id as u64,
+                present_in: DEPLOYMENTS,
+                missing_from: WRAPPED_NATIVE,
+            });
+        }
+        for chain_id in [ChainId::SEPOLIA, ChainId::CELO] {
+            known.push(Finding::MissingChain {
+                chain_id: chain_id as u64,
+                present_in: V2_FACTORY,
+                missing_from: V2_ROUTER,
+            });
+        }
+        assert_eq!(report.findings, known, "{report}");
+    }
+}
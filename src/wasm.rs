@@ -0,0 +1,688 @@
+//! JavaScript bindings generated with [`wasm_bindgen`].
+//!
+//! Big numbers cross the boundary as decimal strings so that no precision is lost to JavaScript
+//! numbers, and chain IDs as `bigint`s. Errors are thrown as JavaScript `Error`s carrying the
+//! message of the [`Error`].
+
+// the exported functions ignore `#[inline]`, which is still required by clippy on native targets
+#![cfg_attr(target_arch = "wasm32", allow(unused_attributes))]
+
+use crate::prelude::*;
+use core::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// An ERC20 token.
+#[wasm_bindgen(js_name = Token)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WasmToken(Token);
+
+#[wasm_bindgen(js_class = Token)]
+impl WasmToken {
+    /// Creates a token, validating its address and decimals.
+    #[wasm_bindgen(constructor)]
+    #[inline]
+    pub fn new(
+        chain_id: u64,
+        address: &str,
+        decimals: u8,
+        symbol: Option<String>,
+        name: Option<String>,
+    ) -> Result<Self, Error> {
+        let mut builder = Token::builder(chain_id)
+            .address_str(address)
+            .decimals(decimals);
+        if let Some(symbol) = symbol {
+            builder = builder.symbol(&symbol);
+        }
+        if let Some(name) = name {
+            builder = builder.name(&name);
+        }
+        builder.build().map(Self)
+    }
+
+    #[wasm_bindgen(getter, js_name = chainId)]
+    #[inline]
+    #[must_use]
+    pub fn chain_id(&self) -> u64 {
+        self.0.chain_id()
+    }
+
+    /// The checksummed address of the token.
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn address(&self) -> String {
+        self.0.address().to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn decimals(&self) -> u8 {
+        self.0.decimals()
+    }
+
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn symbol(&self) -> Option<String> {
+        self.0.symbol.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> Option<String> {
+        self.0.name.clone()
+    }
+
+    /// Returns whether the tokens are on the same chain and have the same address.
+    #[inline]
+    #[must_use]
+    pub fn equals(&self, other: &Self) -> bool {
+        self.0.equals(&other.0)
+    }
+
+    /// Returns whether the address of this token sorts before the address of the other token.
+    #[wasm_bindgen(js_name = sortsBefore)]
+    #[inline]
+    pub fn sorts_before(&self, other: &Self) -> Result<bool, Error> {
+        self.0.sorts_before(&other.0)
+    }
+
+    /// Returns the token as a [`WasmCurrency`].
+    #[wasm_bindgen(js_name = asCurrency)]
+    #[inline]
+    #[must_use]
+    pub fn as_currency(&self) -> WasmCurrency {
+        WasmCurrency(self.0.clone().into())
+    }
+}
+
+/// The native currency of a chain.
+#[wasm_bindgen(js_name = Ether)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WasmEther(Ether);
+
+#[wasm_bindgen(js_class = Ether)]
+impl WasmEther {
+    #[wasm_bindgen(js_name = onChain)]
+    #[inline]
+    #[must_use]
+    pub fn on_chain(chain_id: u64) -> Self {
+        Self(Ether::on_chain(chain_id))
+    }
+
+    #[wasm_bindgen(getter, js_name = chainId)]
+    #[inline]
+    #[must_use]
+    pub fn chain_id(&self) -> u64 {
+        self.0.chain_id()
+    }
+
+    /// The wrapped native token, which is undefined if the chain has none.
+    #[inline]
+    #[must_use]
+    pub fn wrapped(&self) -> Option<WasmToken> {
+        WETH9::on_chain(self.0.chain_id()).map(WasmToken)
+    }
+
+    /// Returns the native currency as a [`WasmCurrency`].
+    #[wasm_bindgen(js_name = asCurrency)]
+    #[inline]
+    #[must_use]
+    pub fn as_currency(&self) -> WasmCurrency {
+        WasmCurrency(self.0.clone().into())
+    }
+}
+
+/// A token or the native currency, used by amounts and prices.
+#[wasm_bindgen(js_name = Currency)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WasmCurrency(Currency);
+
+#[wasm_bindgen(js_class = Currency)]
+impl WasmCurrency {
+    #[wasm_bindgen(getter, js_name = isNative)]
+    #[inline]
+    #[must_use]
+    pub fn is_native(&self) -> bool {
+        self.0.is_native()
+    }
+
+    #[wasm_bindgen(getter, js_name = chainId)]
+    #[inline]
+    #[must_use]
+    pub fn chain_id(&self) -> u64 {
+        self.0.chain_id()
+    }
+
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn decimals(&self) -> u8 {
+        self.0.decimals()
+    }
+
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn symbol(&self) -> Option<String> {
+        self.0.symbol().cloned()
+    }
+
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> Option<String> {
+        self.0.name().cloned()
+    }
+
+    /// The token, which is undefined for the native currency.
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn token(&self) -> Option<WasmToken> {
+        match &self.0 {
+            Currency::Token(token) => Some(WasmToken(token.clone())),
+            Currency::NativeCurrency(_) => None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn equals(&self, other: &Self) -> bool {
+        self.0.equals(&other.0)
+    }
+}
+
+/// An amount of a currency.
+#[wasm_bindgen(js_name = CurrencyAmount)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WasmCurrencyAmount(CurrencyAmount<Currency>);
+
+#[wasm_bindgen(js_class = CurrencyAmount)]
+impl WasmCurrencyAmount {
+    /// Creates an amount from the raw amount of the smallest unit of the currency.
+    #[wasm_bindgen(js_name = fromRawAmount)]
+    #[inline]
+    pub fn from_raw_amount(currency: &WasmCurrency, raw_amount: &str) -> Result<Self, Error> {
+        CurrencyAmount::from_raw_amount(currency.0.clone(), parse_big_int(raw_amount)?).map(Self)
+    }
+
+    #[wasm_bindgen(js_name = fromFractionalAmount)]
+    #[inline]
+    pub fn from_fractional_amount(
+        currency: &WasmCurrency,
+        numerator: &str,
+        denominator: &str,
+    ) -> Result<Self, Error> {
+        let denominator = parse_big_int(denominator)?;
+        if denominator == BigInt::ZERO {
+            return Err(Error::DivisionByZero);
+        }
+        CurrencyAmount::from_fractional_amount(
+            currency.0.clone(),
+            parse_big_int(numerator)?,
+            denominator,
+        )
+        .map(Self)
+    }
+
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn currency(&self) -> WasmCurrency {
+        WasmCurrency(self.0.currency.clone())
+    }
+
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn numerator(&self) -> String {
+        self.0.numerator.to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn denominator(&self) -> String {
+        self.0.denominator.to_string()
+    }
+
+    /// The raw amount, rounded down.
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn quotient(&self) -> String {
+        self.0.quotient().to_string()
+    }
+
+    #[inline]
+    pub fn add(&self, other: &Self) -> Result<Self, Error> {
+        self.0.add(&other.0).map(Self)
+    }
+
+    #[inline]
+    pub fn subtract(&self, other: &Self) -> Result<Self, Error> {
+        self.0.subtract(&other.0).map(Self)
+    }
+
+    #[inline]
+    pub fn multiply(&self, percent: &WasmPercent) -> Result<Self, Error> {
+        self.0.multiply(&percent.0).map(Self)
+    }
+
+    #[wasm_bindgen(js_name = toExact)]
+    #[inline]
+    #[must_use]
+    pub fn to_exact(&self) -> String {
+        self.0.to_exact()
+    }
+
+    #[wasm_bindgen(js_name = toSignificant)]
+    #[inline]
+    pub fn to_significant(
+        &self,
+        significant_digits: u8,
+        rounding: Option<WasmRounding>,
+    ) -> Result<String, Error> {
+        self.0
+            .to_significant(significant_digits, rounding.map(Into::into))
+    }
+
+    #[wasm_bindgen(js_name = toFixed)]
+    #[inline]
+    pub fn to_fixed(
+        &self,
+        decimal_places: u8,
+        rounding: Option<WasmRounding>,
+    ) -> Result<String, Error> {
+        self.0.to_fixed(decimal_places, rounding.map(Into::into))
+    }
+}
+
+/// The price of a base currency in terms of a quote currency.
+#[wasm_bindgen(js_name = Price)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WasmPrice(Price<Currency, Currency>);
+
+#[wasm_bindgen(js_class = Price)]
+impl WasmPrice {
+    /// Creates the price at which `denominator` raw base units trade for `numerator` raw quote
+    /// units.
+    #[wasm_bindgen(constructor)]
+    #[inline]
+    pub fn new(
+        base_currency: &WasmCurrency,
+        quote_currency: &WasmCurrency,
+        denominator: &str,
+        numerator: &str,
+    ) -> Result<Self, Error> {
+        let denominator = parse_big_int(denominator)?;
+        if denominator == BigInt::ZERO {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Self(Price::new(
+            base_currency.0.clone(),
+            quote_currency.0.clone(),
+            denominator,
+            parse_big_int(numerator)?,
+        )))
+    }
+
+    #[wasm_bindgen(js_name = fromCurrencyAmounts)]
+    #[inline]
+    #[must_use]
+    pub fn from_currency_amounts(
+        base_amount: &WasmCurrencyAmount,
+        quote_amount: &WasmCurrencyAmount,
+    ) -> Self {
+        Self(Price::from_currency_amounts(
+            base_amount.0.clone(),
+            quote_amount.0.clone(),
+        ))
+    }
+
+    #[wasm_bindgen(getter, js_name = baseCurrency)]
+    #[inline]
+    #[must_use]
+    pub fn base_currency(&self) -> WasmCurrency {
+        WasmCurrency(self.0.base_currency.clone())
+    }
+
+    #[wasm_bindgen(getter, js_name = quoteCurrency)]
+    #[inline]
+    #[must_use]
+    pub fn quote_currency(&self) -> WasmCurrency {
+        WasmCurrency(self.0.quote_currency.clone())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn invert(&self) -> Self {
+        Self(self.0.invert())
+    }
+
+    #[inline]
+    pub fn multiply(&self, other: &Self) -> Result<Self, Error> {
+        self.0.multiply(&other.0).map(Self)
+    }
+
+    /// Returns the amount of quote currency corresponding to an amount of base currency.
+    #[inline]
+    pub fn quote(&self, amount: &WasmCurrencyAmount) -> Result<WasmCurrencyAmount, Error> {
+        self.0.quote(&amount.0).map(WasmCurrencyAmount)
+    }
+
+    #[wasm_bindgen(js_name = toSignificant)]
+    #[inline]
+    pub fn to_significant(
+        &self,
+        significant_digits: u8,
+        rounding: Option<WasmRounding>,
+    ) -> Result<String, Error> {
+        self.0
+            .to_significant(significant_digits, rounding.map(Into::into))
+    }
+
+    #[wasm_bindgen(js_name = toFixed)]
+    #[inline]
+    #[must_use]
+    pub fn to_fixed(&self, decimal_places: u8, rounding: Option<WasmRounding>) -> String {
+        self.0.to_fixed(decimal_places, rounding.map(Into::into))
+    }
+}
+
+/// A fraction formatted as a percentage.
+#[wasm_bindgen(js_name = Percent)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WasmPercent(Percent);
+
+#[wasm_bindgen(js_class = Percent)]
+impl WasmPercent {
+    #[wasm_bindgen(constructor)]
+    #[inline]
+    pub fn new(numerator: &str, denominator: &str) -> Result<Self, Error> {
+        let denominator = parse_big_int(denominator)?;
+        if denominator == BigInt::ZERO {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Self(Percent::new(parse_big_int(numerator)?, denominator)))
+    }
+
+    /// Parses a percentage such as `"0.3%"`, `"30bps"` or `"3000pips"`.
+    #[inline]
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        Percent::from_str(value).map(Self)
+    }
+
+    #[wasm_bindgen(js_name = fromBps)]
+    #[inline]
+    pub fn from_bps(bps: &str) -> Result<Self, Error> {
+        Ok(Self(Percent::from_bps(parse_big_int(bps)?)))
+    }
+
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn numerator(&self) -> String {
+        self.0.numerator.to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    #[inline]
+    #[must_use]
+    pub fn denominator(&self) -> String {
+        self.0.denominator.to_string()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn add(&self, other: &Self) -> Self {
+        Self(self.0.clone() + other.0.clone())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn subtract(&self, other: &Self) -> Self {
+        Self(self.0.clone() - other.0.clone())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn multiply(&self, other: &Self) -> Self {
+        Self(self.0.clone() * other.0.clone())
+    }
+
+    #[inline]
+    pub fn divide(&self, other: &Self) -> Result<Self, Error> {
+        if other.0.numerator == BigInt::ZERO {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Self(self.0.clone() / other.0.clone()))
+    }
+
+    #[wasm_bindgen(js_name = lessThan)]
+    #[inline]
+    #[must_use]
+    pub fn less_than(&self, other: &Self) -> bool {
+        self.0 < other.0
+    }
+
+    #[wasm_bindgen(js_name = equalTo)]
+    #[inline]
+    #[must_use]
+    pub fn equal_to(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    #[wasm_bindgen(js_name = greaterThan)]
+    #[inline]
+    #[must_use]
+    pub fn greater_than(&self, other: &Self) -> bool {
+        self.0 > other.0
+    }
+
+    #[wasm_bindgen(js_name = toSignificant)]
+    #[inline]
+    pub fn to_significant(
+        &self,
+        significant_digits: u8,
+        rounding: Option<WasmRounding>,
+    ) -> Result<String, Error> {
+        self.0
+            .to_significant(significant_digits, rounding.map(Into::into))
+    }
+
+    #[wasm_bindgen(js_name = toFixed)]
+    #[inline]
+    #[must_use]
+    pub fn to_fixed(&self, decimal_places: u8, rounding: Option<WasmRounding>) -> String {
+        self.0.to_fixed(decimal_places, rounding.map(Into::into))
+    }
+}
+
+/// Returns the percent difference between the mid price and the execution price of a trade.
+#[wasm_bindgen(js_name = computePriceImpact)]
+#[inline]
+pub fn wasm_compute_price_impact(
+    mid_price: &WasmPrice,
+    input_amount: &WasmCurrencyAmount,
+    output_amount: &WasmCurrencyAmount,
+) -> Result<WasmPercent, Error> {
+    compute_price_impact(&mid_price.0, &input_amount.0, &output_amount.0).map(WasmPercent)
+}
+
+/// The rounding modes of the SDK, see [`Rounding`].
+#[wasm_bindgen(js_name = Rounding)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WasmRounding {
+    RoundDown,
+    RoundHalfUp,
+    RoundUp,
+}
+
+impl From<WasmRounding> for Rounding {
+    #[inline]
+    fn from(rounding: WasmRounding) -> Self {
+        match rounding {
+            WasmRounding::RoundDown => Self::RoundDown,
+            WasmRounding::RoundHalfUp => Self::RoundHalfUp,
+            WasmRounding::RoundUp => Self::RoundUp,
+        }
+    }
+}
+
+/// The address maps of the SDK, see [`AddressMapKind`].
+#[wasm_bindgen(js_name = AddressMapKind)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WasmAddressMapKind {
+    Uni,
+    V2Factory,
+    V2Router,
+    V3CoreFactory,
+    V3Migrator,
+    Multicall,
+    GovernanceAlphaV0,
+    GovernanceAlphaV1,
+    GovernanceBravo,
+    Timelock,
+    MerkleDistributor,
+    ArgentWalletDetector,
+    Quoter,
+    QuoterV2,
+    NonfungiblePositionManager,
+    EnsRegistrar,
+    SocksController,
+    TickLens,
+    MixedRouteQuoterV1,
+    SwapRouter02,
+}
+
+impl From<WasmAddressMapKind> for AddressMapKind {
+    #[inline]
+    fn from(kind: WasmAddressMapKind) -> Self {
+        match kind {
+            WasmAddressMapKind::Uni => Self::Uni,
+            WasmAddressMapKind::V2Factory => Self::V2Factory,
+            WasmAddressMapKind::V2Router => Self::V2Router,
+            WasmAddressMapKind::V3CoreFactory => Self::V3CoreFactory,
+            WasmAddressMapKind::V3Migrator => Self::V3Migrator,
+            WasmAddressMapKind::Multicall => Self::Multicall,
+            WasmAddressMapKind::GovernanceAlphaV0 => Self::GovernanceAlphaV0,
+            WasmAddressMapKind::GovernanceAlphaV1 => Self::GovernanceAlphaV1,
+            WasmAddressMapKind::GovernanceBravo => Self::GovernanceBravo,
+            WasmAddressMapKind::Timelock => Self::Timelock,
+            WasmAddressMapKind::MerkleDistributor => Self::MerkleDistributor,
+            WasmAddressMapKind::ArgentWalletDetector => Self::ArgentWalletDetector,
+            WasmAddressMapKind::Quoter => Self::Quoter,
+            WasmAddressMapKind::QuoterV2 => Self::QuoterV2,
+            WasmAddressMapKind::NonfungiblePositionManager => Self::NonfungiblePositionManager,
+            WasmAddressMapKind::EnsRegistrar => Self::EnsRegistrar,
+            WasmAddressMapKind::SocksController => Self::SocksController,
+            WasmAddressMapKind::TickLens => Self::TickLens,
+            WasmAddressMapKind::MixedRouteQuoterV1 => Self::MixedRouteQuoterV1,
+            WasmAddressMapKind::SwapRouter02 => Self::SwapRouter02,
+        }
+    }
+}
+
+/// Returns the checksummed address of the contract on the chain, or undefined if it is not
+/// deployed there.
+#[wasm_bindgen(js_name = getAddress)]
+#[inline]
+#[must_use]
+pub fn get_address(kind: WasmAddressMapKind, chain_id: u64) -> Option<String> {
+    AddressMapKind::from(kind)
+        .map()
+        .get(&chain_id)
+        .map(ToString::to_string)
+}
+
+/// Returns the IDs of the chains on which the contract is deployed.
+#[wasm_bindgen(js_name = getChainIds)]
+#[inline]
+#[must_use]
+pub fn get_chain_ids(kind: WasmAddressMapKind) -> Vec<u64> {
+    AddressMapKind::from(kind).map().keys().copied().collect()
+}
+
+impl From<Error> for JsValue {
+    #[inline]
+    fn from(error: Error) -> Self {
+        JsError::from(error).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    const DAI: &str = "0x6B175474E89094C44Da98b954EedeAC495271d0F";
+
+    fn dai() -> WasmToken {
+        WasmToken::new(1, &DAI.to_lowercase(), 18, Some("DAI".to_string()), None).unwrap()
+    }
+
+    #[test]
+    fn test_token() {
+        let dai = dai();
+        assert_eq!(dai.address(), DAI);
+        assert_eq!(dai.symbol().as_deref(), Some("DAI"));
+        assert_eq!(dai.name(), None);
+        let weth = WasmEther::on_chain(1).wrapped().unwrap();
+        assert!(dai.sorts_before(&weth).unwrap());
+        assert!(WasmEther::on_chain(1).as_currency().is_native());
+        assert_eq!(dai.as_currency().token(), Some(dai));
+    }
+
+    #[test]
+    fn test_currency_amount() {
+        let dai = dai().as_currency();
+        let amount = WasmCurrencyAmount::from_raw_amount(&dai, "1500000000000000000").unwrap();
+        let sum = amount.add(&amount).unwrap();
+        assert_eq!(sum.to_exact(), "3");
+        assert_eq!(sum.quotient(), "3000000000000000000");
+        let fee = sum.multiply(&WasmPercent::parse("0.3%").unwrap()).unwrap();
+        assert_eq!(fee.to_significant(3, None).unwrap(), "0.009");
+        assert_eq!(
+            WasmCurrencyAmount::from_fractional_amount(&dai, "1", "3")
+                .unwrap()
+                .to_fixed(2, Some(WasmRounding::RoundUp))
+                .unwrap(),
+            "0.00"
+        );
+    }
+
+    #[test]
+    fn test_price_impact() {
+        let dai = dai().as_currency();
+        let ether = WasmEther::on_chain(1).as_currency();
+        // 1 ETH = 2000 DAI
+        let mid_price = WasmPrice::new(&ether, &dai, "1", "2000").unwrap();
+        let input = WasmCurrencyAmount::from_raw_amount(&ether, "1000000000000000000").unwrap();
+        let output = WasmCurrencyAmount::from_raw_amount(&dai, "1990000000000000000000").unwrap();
+        assert_eq!(mid_price.quote(&input).unwrap().to_exact(), "2000");
+        assert_eq!(
+            mid_price.invert().to_significant(2, None).unwrap(),
+            "0.0005"
+        );
+        let impact = wasm_compute_price_impact(&mid_price, &input, &output).unwrap();
+        assert_eq!(impact.to_fixed(2, None), "0.50");
+        assert!(impact.less_than(&WasmPercent::from_bps("100").unwrap()));
+    }
+
+    #[test]
+    fn test_addresses() {
+        assert_eq!(
+            get_address(WasmAddressMapKind::V3CoreFactory, 1).as_deref(),
+            Some("0x1F98431c8aD98523631AE4a59f267346ea31F984")
+        );
+        assert_eq!(get_address(WasmAddressMapKind::GovernanceBravo, 10), None);
+        assert_eq!(get_chain_ids(WasmAddressMapKind::GovernanceBravo), vec![1]);
+    }
+}